@@ -0,0 +1,95 @@
+use criterion::{criterion_group, BatchSize, Criterion};
+use plotters::coord::geo::{
+    CoordError, LambertConformal, LatLonCoord, Mercator, ProjectionS, TileCache,
+};
+
+type Range = (f64, f64);
+
+/// Mercator, with the bounding box going through the dense sampling
+struct SampledMercator(Mercator);
+
+impl ProjectionS for SampledMercator {
+    fn map(&self, v: Range) -> Range {
+        self.0.map(v)
+    }
+
+    fn try_map(&self, v: Range) -> Result<Range, CoordError> {
+        self.0.try_map(v)
+    }
+}
+
+fn bbox_cylindrical(c: &mut Criterion) {
+    let mut group = c.benchmark_group("geo::bbox_cylindrical");
+
+    let mercator = Mercator::new().build();
+    group.bench_function("corners", |b| {
+        b.iter(|| mercator.bbox(Some((-30.0, 60.0)), Some((-45.0, 70.0))))
+    });
+
+    let sampled = SampledMercator(Mercator::new().build());
+    group.bench_function("sampled", |b| {
+        b.iter(|| sampled.bbox(Some((-30.0, 60.0)), Some((-45.0, 70.0))))
+    });
+}
+
+fn bbox_conic(c: &mut Criterion) {
+    let lcc = LambertConformal::new().build();
+    c.bench_function("geo::bbox_conic", |b| {
+        b.iter(|| lcc.bbox(Some((-30.0, 60.0)), Some((20.0, 70.0))))
+    });
+}
+
+criterion_group! {
+    name = bbox_group;
+    config = Criterion::default();
+    targets = bbox_cylindrical, bbox_conic
+}
+
+/// A cache holding a coastline-like line every degree over the globe
+fn filled_cache() -> TileCache {
+    let mut cache = TileCache::new(6);
+    for lon in -180..179 {
+        for lat in -80..79 {
+            let (lon, lat) = (lon as f64, lat as f64);
+            cache.insert(vec![
+                (lon, lat),
+                (lon + 0.5, lat + 0.3),
+                (lon + 0.9, lat + 0.8),
+            ]);
+        }
+    }
+    cache
+}
+
+fn europe(lon: Range) -> LatLonCoord<Mercator> {
+    LatLonCoord::new(
+        Some(lon),
+        Some((35.0, 60.0)),
+        (0..800, 0..600),
+        Mercator::new().build(),
+    )
+}
+
+fn tile_cache(c: &mut Criterion) {
+    let mut group = c.benchmark_group("geo::tile_cache");
+
+    let coord = europe((-10.0, 20.0));
+    group.bench_function("initial", |b| {
+        b.iter_batched(
+            filled_cache,
+            |mut cache| cache.lines(&coord),
+            BatchSize::LargeInput,
+        )
+    });
+
+    let mut cache = filled_cache();
+    cache.lines(&coord);
+    let panned = europe((-5.0, 25.0));
+    group.bench_function("panned", |b| b.iter(|| cache.lines(&panned)));
+}
+
+criterion_group! {
+    name = tile_cache_group;
+    config = Criterion::default().sample_size(10);
+    targets = tile_cache
+}
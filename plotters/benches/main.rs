@@ -3,5 +3,7 @@ use criterion::criterion_main;
 mod benches;
 
 criterion_main! {
-    benches::data::quartiles_group
+    benches::data::quartiles_group,
+    benches::geo::bbox_group,
+    benches::geo::tile_cache_group
 }
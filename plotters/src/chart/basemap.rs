@@ -0,0 +1,161 @@
+use super::context::ChartContext;
+use crate::coord::geo::{graticule_values, normalize_lon, LatLonCoord, ProjectionS};
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::element::{PathElement, Polygon, Text};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, RGBColor, ShapeStyle, TextStyle, BLACK};
+
+use plotters_backend::DrawingBackend;
+
+/// The style of a basemap drawn by [`draw_basemap`]
+pub struct BasemapStyle<'a> {
+    /// The fill of the map frame
+    pub ocean: ShapeStyle,
+    /// The style of the graticule lines
+    pub graticule: ShapeStyle,
+    /// The style of the stroke around the map frame
+    pub border: ShapeStyle,
+    /// The style of the graticule labels along the edges of the map
+    pub label: TextStyle<'a>,
+    /// The spacing between two meridians, in degrees
+    pub lon_step: f64,
+    /// The spacing between two parallels, in degrees
+    pub lat_step: f64,
+}
+
+impl<'a> Default for BasemapStyle<'a> {
+    fn default() -> Self {
+        Self {
+            ocean: RGBColor(0xd4, 0xe6, 0xf1).filled(),
+            graticule: BLACK.mix(0.2).into(),
+            border: BLACK.into(),
+            label: ("sans-serif", 12).into(),
+            lon_step: 30.0,
+            lat_step: 30.0,
+        }
+    }
+}
+
+/**
+Draws a complete basemap on `area` and returns the chart context, ready for data series.
+
+The map `coord` is fitted to the whole area. Its frame, the outline of bounded projections
+or the projected window, is filled as ocean, then the graticule is drawn, the frame is
+stroked, and the meridians and parallels are labelled where they leave the map at the
+bottom and left edges.
+*/
+pub fn draw_basemap<'a, DB: DrawingBackend, T: ProjectionS>(
+    area: &DrawingArea<DB, Shift>,
+    mut coord: LatLonCoord<T>,
+    style: &BasemapStyle,
+) -> Result<ChartContext<'a, DB, LatLonCoord<T>>, DrawingAreaErrorKind<DB::ErrorType>> {
+    coord.resize(area.geo_pixel_range());
+    let screen = area.use_screen_coord();
+
+    let mut boundary = coord.boundary();
+    screen.draw(&Polygon::new(boundary.clone(), style.ocean))?;
+
+    for line in coord.graticule_lines(style.lon_step, style.lat_step) {
+        screen.draw(&PathElement::new(line, style.graticule))?;
+    }
+
+    boundary.extend(boundary.first().copied());
+    screen.draw(&PathElement::new(boundary, style.border))?;
+
+    let (lon, lat) = coord.extent();
+    for value in graticule_values(lon, style.lon_step) {
+        if let Some(&(x, y)) = coord.meridian_line(value).first() {
+            let label = style.label.pos(Pos::new(HPos::Center, VPos::Bottom));
+            screen.draw(&Text::new(format_lon(value), (x, y - 2), label))?;
+        }
+    }
+    for value in graticule_values(lat, style.lat_step) {
+        if let Some(&(x, y)) = coord.parallel_line(value).first() {
+            let label = style.label.pos(Pos::new(HPos::Left, VPos::Center));
+            screen.draw(&Text::new(format_lat(value), (x + 2, y), label))?;
+        }
+    }
+
+    let (x0, y0) = area.get_base_pixel();
+    Ok(ChartContext {
+        x_label_area: [None, None],
+        y_label_area: [None, None],
+        drawing_area: area.apply_coord_spec(coord),
+        series_anno: vec![],
+        drawing_area_pos: (x0, y0),
+    })
+}
+
+/// The label of a meridian, e.g. `30°E`, also for meridians past ±180° on maps
+/// [wrapping the longitudes](LatLonCoord::wrap_longitude)
+pub(super) fn format_lon(lon: f64) -> String {
+    match normalize_lon(lon) {
+        v if v > 0.0 && v < 180.0 => format!("{}°E", v),
+        v if v < 0.0 && v > -180.0 => format!("{}°W", -v),
+        v => format!("{}°", v.abs()),
+    }
+}
+
+/// The label of a parallel, e.g. `45°S`
+pub(super) fn format_lat(lat: f64) -> String {
+    match lat {
+        v if v > 0.0 => format!("{}°N", v),
+        v if v < 0.0 => format!("{}°S", -v),
+        _ => "0°".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "proj")]
+    use crate::coord::geo::{Mercator, Orthographic};
+    #[cfg(feature = "proj")]
+    use crate::prelude::*;
+
+    #[test]
+    fn test_format_labels() {
+        assert_eq!(format_lon(30.0), "30°E");
+        assert_eq!(format_lon(-120.0), "120°W");
+        assert_eq!(format_lon(180.0), "180°");
+        assert_eq!(format_lon(210.0), "150°W");
+        assert_eq!(format_lon(360.0), "0°");
+        assert_eq!(format_lon(-190.0), "170°E");
+        assert_eq!(format_lat(-45.0), "45°S");
+        assert_eq!(format_lat(0.0), "0°");
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_draw_basemap() {
+        let drawing_area = create_mocked_drawing_area(400, 300, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                // 7 meridians, 5 parallels and the border
+                assert_eq!(b.num_draw_path_call, 13);
+                assert_eq!(b.num_draw_text_call, 12);
+            });
+        });
+        let coord = LatLonCoord::new(
+            Some((-90.0, 90.0)),
+            Some((-60.0, 60.0)),
+            (0..1, 0..1),
+            Mercator::new().build(),
+        );
+        let mut chart = draw_basemap(&drawing_area, coord, &BasemapStyle::default()).unwrap();
+        chart
+            .draw_series(std::iter::once(Circle::new((0.0, 0.0), 3, RED)))
+            .unwrap();
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_draw_globe_basemap() {
+        let drawing_area = create_mocked_drawing_area(300, 300, |m| {
+            m.check_fill_polygon(|_, points| assert!(points.len() > 100));
+        });
+        let coord = LatLonCoord::new(None, None, (0..1, 0..1), Orthographic::new().build());
+        draw_basemap(&drawing_area, coord, &BasemapStyle::default()).unwrap();
+    }
+}
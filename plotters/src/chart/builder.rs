@@ -1,6 +1,7 @@
 use super::context::ChartContext;
 
 use crate::coord::cartesian::{Cartesian2d, Cartesian3d, LatLonCoord, ProjectionS};
+use crate::coord::geo::{CoordError, PlateCarree};
 use crate::coord::ranged1d::AsRangedCoord;
 use crate::coord::CoordTranslate;
 use crate::coord::Shift;
@@ -10,6 +11,8 @@ use crate::style::{IntoTextStyle, SizeDesc, TextStyle};
 
 use plotters_backend::DrawingBackend;
 
+use std::ops::Range;
+
 /**
 Specifies one of the four label positions around the figure.
 
@@ -443,8 +446,9 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
     /**
     Builds a chart with a Geo coordinate system.
 
-    - `x_spec`: Specifies the X axis range and data properties
-    - `y_spec`: Specifies the Y axis range and data properties
+    - `lon`: The longitude extent of the map, `None` for the projection's natural extent
+    - `lat`: The latitude extent of the map, `None` for the projection's natural extent
+    - `proj`: The projection the series are drawn with
     - Returns: A `ChartContext` object, ready to visualize data.
 
     See [`ChartBuilder::on()`] and [`ChartContext::configure_mesh()`] for more information and examples.
@@ -454,6 +458,55 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         lon: Option<(f64, f64)>,
         lat: Option<(f64, f64)>,
         proj: T,
+    ) -> Result<ChartContext<'a, DB, LatLonCoord<T>>, DrawingAreaErrorKind<DB::ErrorType>> {
+        self.build_geo_chart(|pixel_range| Ok(LatLonCoord::new(lon, lat, pixel_range, proj)))
+    }
+
+    /**
+    Builds a chart with a Geo coordinate system, like [`ChartBuilder::build_geo_coord()`], but
+    without panicking: an extent the projection can't bound, e.g. with a projection whose
+    PROJ transformation wasn't built, is returned as a [`DrawingAreaErrorKind::CoordError`]
+    along with the errors of the layout. So is an extent reaching past the latitudes the
+    projection can show, as the [`CoordError::Clamped`] warning holding the latitudes
+    `build_geo_coord` falls back to.
+
+    ```rust
+    use plotters::prelude::*;
+    use plotters::coord::geo::{CoordError, EqualEarth, Mercator};
+    use plotters::drawing::DrawingAreaErrorKind;
+
+    let area = SVGBackend::new("try_geo.svg", (400, 300)).into_drawing_area();
+    let result = ChartBuilder::on(&area).try_build_geo_coord(None, None, EqualEarth::new());
+    assert!(result.is_err());
+
+    // Mercator can't reach the poles
+    let pole_to_pole = Some((-90.0, 90.0));
+    let result =
+        ChartBuilder::on(&area).try_build_geo_coord(None, pole_to_pole, Mercator::new().build());
+    let band = match result {
+        Err(DrawingAreaErrorKind::CoordError(CoordError::Clamped(band))) => band,
+        _ => panic!("the latitudes aren't clamped"),
+    };
+    let chart = ChartBuilder::on(&area)
+        .build_geo_coord(None, pole_to_pole, Mercator::new().build())
+        .unwrap();
+    assert_eq!(chart.as_coord_spec().lat, Some(band));
+    ```
+    */
+    pub fn try_build_geo_coord<T: ProjectionS>(
+        &mut self,
+        lon: Option<(f64, f64)>,
+        lat: Option<(f64, f64)>,
+        proj: T,
+    ) -> Result<ChartContext<'a, DB, LatLonCoord<T>>, DrawingAreaErrorKind<DB::ErrorType>> {
+        self.build_geo_chart(|pixel_range| LatLonCoord::try_new(lon, lat, pixel_range, proj))
+    }
+
+    /// Lays the chart out and puts the map `coord` makes from the pixel range of the plotting
+    /// area on it
+    fn build_geo_chart<T: ProjectionS>(
+        &mut self,
+        coord: impl FnOnce((Range<i32>, Range<i32>)) -> Result<LatLonCoord<T>, CoordError>,
     ) -> Result<ChartContext<'a, DB, LatLonCoord<T>>, DrawingAreaErrorKind<DB::ErrorType>> {
         let mut label_areas = [None, None, None, None];
 
@@ -567,12 +620,7 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         Ok(ChartContext {
             x_label_area,
             y_label_area,
-            drawing_area: drawing_area.apply_coord_spec(LatLonCoord::new(
-                lon,
-                lat,
-                pixel_range,
-                proj,
-            )),
+            drawing_area: drawing_area.apply_coord_spec(coord(pixel_range)?),
             series_anno: vec![],
             drawing_area_pos: (
                 actual_drawing_area_pos[2] + title_dx + self.margin[2] as i32,
@@ -581,6 +629,36 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         })
     }
 
+    /**
+    Builds a chart plotting lon/lat degrees as they are, with the identity
+    [`PlateCarree::geographic`] projection. It is computed in Rust, so this is also available
+    when the crate is built without the `proj` feature, which links the PROJ library.
+
+    - `lon`: The longitude range of the chart
+    - `lat`: The latitude range of the chart
+    - Returns: A `ChartContext` object, ready to visualize data.
+
+    ```rust
+    use plotters::prelude::*;
+
+    let area = SVGBackend::new("lonlat.svg", (360, 180)).into_drawing_area();
+    let mut chart = ChartBuilder::on(&area)
+        .build_lonlat((-180.0, 180.0), (-90.0, 90.0))
+        .unwrap();
+    chart
+        .draw_series(std::iter::once(Circle::new((2.35, 48.85), 3, RED.filled())))
+        .unwrap();
+    ```
+    */
+    pub fn build_lonlat(
+        &mut self,
+        lon: (f64, f64),
+        lat: (f64, f64),
+    ) -> Result<ChartContext<'a, DB, LatLonCoord<PlateCarree>>, DrawingAreaErrorKind<DB::ErrorType>>
+    {
+        self.build_geo_coord(Some(lon), Some(lat), PlateCarree::geographic())
+    }
+
     /**
     Builds a chart with a 3D Cartesian coordinate system.
 
@@ -692,6 +770,52 @@ mod test {
         assert_eq!(chart.margin[3], 13);
     }
 
+    #[test]
+    fn test_build_lonlat() {
+        let drawing_area = create_mocked_drawing_area(361, 181, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_lonlat((-180.0, 180.0), (-90.0, 90.0))
+            .unwrap();
+        // One degree per pixel, north up
+        let coord = chart.as_coord_spec();
+        assert_eq!(coord.translate(&(0.0, 0.0)), (180, 90));
+        assert_eq!(coord.translate(&(-180.0, 90.0)), (0, 0));
+        assert_eq!(coord.translate(&(30.0, -45.0)), (210, 135));
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_try_build_geo_coord() {
+        use crate::coord::geo::Mercator;
+
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let chart = ChartBuilder::on(&drawing_area).try_build_geo_coord(
+            None,
+            Some((-60.0, 60.0)),
+            Mercator::new().build(),
+        );
+        assert!(chart.is_ok());
+
+        // The projection can't be used before it is built
+        let chart = ChartBuilder::on(&drawing_area).try_build_geo_coord(
+            None,
+            Some((-60.0, 60.0)),
+            Mercator::new(),
+        );
+        assert!(matches!(chart, Err(DrawingAreaErrorKind::CoordError(_))));
+
+        // Mercator can't reach the poles, the warning holds the band it can show
+        let chart = ChartBuilder::on(&drawing_area).try_build_geo_coord(
+            None,
+            Some((-90.0, 90.0)),
+            Mercator::new().build(),
+        );
+        assert!(matches!(
+            chart,
+            Err(DrawingAreaErrorKind::CoordError(CoordError::Clamped(band))) if band == (-80.0, 84.0)
+        ));
+    }
+
     #[test]
     fn test_caption() {
         let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
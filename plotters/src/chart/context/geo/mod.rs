@@ -0,0 +1,767 @@
+use plotters_backend::{BackendCoord, DrawingBackend};
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+
+use crate::chart::basemap::{format_lat, format_lon};
+use crate::chart::{ChartContext, GeoLines, GraticuleStyle};
+use crate::coord::geo::{
+    graticule_values, DistortionMetric, FrameEdge, Grid2D, LatLonCoord, ProjectionS,
+};
+use crate::coord::{CoordTranslate, ReverseCoordTranslate};
+use crate::drawing::{DrawingAreaErrorKind, Rect};
+use crate::element::{Circle, PathElement, Polygon, Rectangle, Text};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, RGBAColor, RGBColor, ShapeStyle, TextStyle};
+
+impl<'a, DB: DrawingBackend, T: ProjectionS> ChartContext<'a, DB, LatLonCoord<T>> {
+    /// Initialize a graticule configuration object, the graticule drawing can be finalized by
+    /// calling the function `GraticuleStyle::draw`.
+    pub fn configure_graticule(&mut self) -> GraticuleStyle<'a, '_, DB, T> {
+        GraticuleStyle::new(self)
+    }
+
+    /// Draws lon/lat ticks along the frame of the map instead of a full graticule: every
+    /// meridian at a multiple of `lon_step` and parallel at a multiple of `lat_step` gets a
+    /// `tick_len_px` long tick inward where it crosses the frame, labelled outside of it.
+    /// Labels overlapping each other, mostly around the corners where the labels of two
+    /// edges meet, are handled following `collisions`, the meridian labels coming first.
+    pub fn draw_frame_ticks(
+        &mut self,
+        lon_step: f64,
+        lat_step: f64,
+        tick_len_px: i32,
+        text_style: &TextStyle,
+        collisions: LabelCollisionPolicy,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let coord = self.drawing_area.as_coord_spec();
+        let screen = self.drawing_area.use_screen_coord();
+
+        let color = text_style.color;
+        let tick_style = ShapeStyle {
+            color: RGBAColor(color.rgb.0, color.rgb.1, color.rgb.2, color.alpha),
+            filled: false,
+            stroke_width: 1,
+        };
+
+        let (lon, lat) = coord.extent();
+        let meridians = graticule_values(lon, lon_step)
+            .map(|v| (format_lon(v), coord.meridian_frame_crossings(v)));
+        let parallels = graticule_values(lat, lat_step)
+            .map(|v| (format_lat(v), coord.parallel_frame_crossings(v)));
+
+        let mut placed: Vec<(BackendCoord, BackendCoord)> = vec![];
+        for (label, crossings) in meridians.chain(parallels) {
+            for (edge, (x, y)) in crossings {
+                let (inward, outward, pos) = match edge {
+                    FrameEdge::Top => ((0, 1), (0, -2), Pos::new(HPos::Center, VPos::Bottom)),
+                    FrameEdge::Bottom => ((0, -1), (0, 2), Pos::new(HPos::Center, VPos::Top)),
+                    FrameEdge::Left => ((1, 0), (-2, 0), Pos::new(HPos::Right, VPos::Center)),
+                    FrameEdge::Right => ((-1, 0), (2, 0), Pos::new(HPos::Left, VPos::Center)),
+                };
+                let tip = (x + inward.0 * tick_len_px, y + inward.1 * tick_len_px);
+                screen.draw(&PathElement::new(vec![(x, y), tip], tick_style))?;
+
+                let mut anchor = (x + outward.0, y + outward.1);
+                let size = self
+                    .drawing_area
+                    .estimate_text_size(&label, text_style)
+                    .unwrap_or((0, 0));
+                let mut label_box = text_box(anchor, size, pos);
+                if collisions != LabelCollisionPolicy::AllowOverlap {
+                    // Labels slide along their edge, across the outward direction
+                    let along_x = outward.0 == 0;
+                    for _ in 0..=placed.len() {
+                        let other = match placed.iter().find(|p| boxes_overlap(**p, label_box)) {
+                            Some(other) => *other,
+                            None => break,
+                        };
+                        if collisions == LabelCollisionPolicy::DropLower {
+                            break;
+                        }
+                        let (lo, hi, other_lo, other_hi) = if along_x {
+                            (label_box.0 .0, label_box.1 .0, other.0 .0, other.1 .0)
+                        } else {
+                            (label_box.0 .1, label_box.1 .1, other.0 .1, other.1 .1)
+                        };
+                        let shift = if lo + hi < other_lo + other_hi {
+                            other_lo - hi - 1
+                        } else {
+                            other_hi - lo + 1
+                        };
+                        if along_x {
+                            anchor.0 += shift;
+                        } else {
+                            anchor.1 += shift;
+                        }
+                        label_box = text_box(anchor, size, pos);
+                    }
+                    if collisions == LabelCollisionPolicy::DropLower
+                        && placed.iter().any(|p| boxes_overlap(*p, label_box))
+                    {
+                        continue;
+                    }
+                }
+                placed.push(label_box);
+                screen.draw(&Text::new(label.clone(), anchor, text_style.pos(pos)))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Draws a dot at each `(lon, lat)` marker with its label away from it, at `offset_px`
+    /// from the projected marker and joined to it by a leader line in `line_style`. A label
+    /// that would overlap one drawn before is pushed further along the offset, up to four
+    /// times `offset_px` away, so clustered markers stay readable. Markers off the map are
+    /// skipped.
+    pub fn draw_markers_with_leaders(
+        &mut self,
+        markers: &[((f64, f64), String)],
+        offset_px: (i32, i32),
+        line_style: ShapeStyle,
+        text_style: &TextStyle,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let coord = self.drawing_area.as_coord_spec();
+        let screen = self.drawing_area.use_screen_coord();
+
+        // The label grows away from the marker
+        let pos = Pos::new(
+            if offset_px.0 < 0 {
+                HPos::Right
+            } else {
+                HPos::Left
+            },
+            if offset_px.1 < 0 {
+                VPos::Bottom
+            } else {
+                VPos::Top
+            },
+        );
+        let mut placed: Vec<(BackendCoord, BackendCoord)> = vec![];
+        for (point, label) in markers {
+            if !coord.is_visible(*point) {
+                continue;
+            }
+            let marker = coord.translate(point);
+            let (w, h) = self
+                .drawing_area
+                .estimate_text_size(label, text_style)
+                .unwrap_or((0, 0));
+            let label_box = |k: i32| {
+                let anchor = (marker.0 + k * offset_px.0, marker.1 + k * offset_px.1);
+                let (x0, x1) = match pos.h_pos {
+                    HPos::Right => (anchor.0 - w as i32, anchor.0),
+                    _ => (anchor.0, anchor.0 + w as i32),
+                };
+                let (y0, y1) = match pos.v_pos {
+                    VPos::Bottom => (anchor.1 - h as i32, anchor.1),
+                    _ => (anchor.1, anchor.1 + h as i32),
+                };
+                (anchor, ((x0, y0), (x1, y1)))
+            };
+            let (anchor, label_box) = (1..=MAX_LEADER_STEPS)
+                .map(label_box)
+                .find(|(_, b)| !placed.iter().any(|p| boxes_overlap(*p, *b)))
+                .unwrap_or_else(|| label_box(MAX_LEADER_STEPS));
+            placed.push(label_box);
+
+            screen.draw(&PathElement::new(vec![marker, anchor], line_style))?;
+            screen.draw(&Circle::new(marker, 2, line_style.filled()))?;
+            screen.draw(&Text::new(label.clone(), anchor, text_style.pos(pos)))?;
+        }
+        Ok(())
+    }
+
+    /// Fills the night side of the map at `time` with `night_style`, bounded by the
+    /// [terminator](LatLonCoord::terminator_line) computed from the subsolar point
+    #[cfg(feature = "chrono")]
+    pub fn draw_terminator(
+        &mut self,
+        time: DateTime<Utc>,
+        night_style: ShapeStyle,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let coord = self.drawing_area.as_coord_spec();
+        let screen = self.drawing_area.use_screen_coord();
+        let night = coord.night_polygon(&time);
+        screen.draw(&coord.project_polygon(&night, night_style))?;
+        Ok(())
+    }
+
+    /// Draws the scalar field `grid`, spanning the `(lon, lat)` box `grid_bounds` (see
+    /// [`Grid2D`]), resampled on the pixels of the map: each pixel is mapped back to lon/lat
+    /// with the inverse projection, the grid is bilinearly interpolated there, and the pixel
+    /// gets the color `gradient` gives for the value. Pixels off the map or the grid are left
+    /// as they are.
+    ///
+    /// With a `downsample` factor above one, only one pixel in `downsample` along each axis is
+    /// sampled, filling a square block, which is much faster for previews.
+    pub fn draw_gridded_field(
+        &mut self,
+        grid: &Grid2D<f64>,
+        grid_bounds: ((f64, f64), (f64, f64)),
+        gradient: impl Fn(f64) -> RGBColor,
+        downsample: u32,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let coord = self.drawing_area.as_coord_spec();
+        let screen = self.drawing_area.use_screen_coord();
+
+        let block = downsample.max(1) as i32;
+        let (x_range, y_range) = self.drawing_area.get_pixel_range();
+        for y in y_range.step_by(block as usize) {
+            for x in x_range.clone().step_by(block as usize) {
+                let center = (x + block / 2, y + block / 2);
+                let value = coord
+                    .reverse_translate(center)
+                    .and_then(|point| grid.sample(grid_bounds, point));
+                if let Some(value) = value {
+                    let corners = [(x, y), (x + block - 1, y + block - 1)];
+                    screen.draw(&Rectangle::new(corners, gradient(value).filled()))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Shades the map with the local distortion of the projection: the extent is split into
+    /// `cell_deg` wide lon/lat cells, and each cell whose center is visible is filled with the
+    /// color `gradient` gives for the [distortion](LatLonCoord::distortion_at) at its center.
+    /// `gradient` gets the raw value of `metric`, e.g. `4.0` where Mercator quadruples areas.
+    pub fn draw_distortion_overlay(
+        &mut self,
+        metric: DistortionMetric,
+        gradient: impl Fn(f64) -> RGBColor,
+        cell_deg: f64,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let coord = self.drawing_area.as_coord_spec();
+        let screen = self.drawing_area.use_screen_coord();
+
+        let ((w, e), (s, n)) = coord.extent();
+        let cells = |a: f64, b: f64| {
+            let count = ((b - a) / cell_deg).ceil().max(1.0) as usize;
+            (0..count).map(move |i| {
+                (
+                    a + i as f64 * cell_deg,
+                    (a + (i + 1) as f64 * cell_deg).min(b),
+                )
+            })
+        };
+        for (lon0, lon1) in cells(w, e) {
+            for (lat0, lat1) in cells(s, n) {
+                let center = ((lon0 + lon1) / 2.0, (lat0 + lat1) / 2.0);
+                if !coord.is_visible_degrees(center) {
+                    continue;
+                }
+                let color = gradient(coord.distortion_at(center, metric));
+                let ring = [(lon0, lat0), (lon1, lat0), (lon1, lat1), (lon0, lat1)];
+                screen.draw(&coord.project_polygon(&ring, color.filled()))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Draws a polygon layer whose style depends on each feature, for thematic maps. A
+    /// feature is made of its `(lon, lat)` rings and its properties, e.g. a population or a
+    /// category, and `style_fn` gives the style of each feature from its properties. The
+    /// rings of a feature are filled if the style is, else stroked.
+    ///
+    /// GeoJSON isn't read here, as the crate doesn't depend on a GeoJSON parser: convert
+    /// each GeoJSON feature to its rings and the properties the style needs first.
+    pub fn draw_polygons_styled<P>(
+        &mut self,
+        features: &[(GeoLines, P)],
+        style_fn: impl Fn(&P) -> ShapeStyle,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let coord = self.drawing_area.as_coord_spec();
+        let screen = self.drawing_area.use_screen_coord();
+
+        for (rings, properties) in features {
+            let style = style_fn(properties);
+            for ring in rings {
+                if style.filled {
+                    screen.draw(&coord.project_polygon(ring, style))?;
+                } else {
+                    let closed: Vec<_> = ring.iter().chain(ring.first()).copied().collect();
+                    for path in coord.project_path(&closed, style) {
+                        screen.draw(&path)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Draws the disk of the globe, for projections whose outline is a circle, such as
+    /// [`Orthographic`](crate::coord::geo::Orthographic): the disk is filled with `fill` and
+    /// its edge stroked with `stroke`, clipped to the frame. Draw it first, to lay the globe
+    /// behind the other layers. Maps of other projections are left as they are.
+    pub fn draw_globe_disk(
+        &mut self,
+        fill: ShapeStyle,
+        stroke: ShapeStyle,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let coord = self.drawing_area.as_coord_spec();
+        let screen = self.drawing_area.use_screen_coord();
+
+        if coord.globe_disk().is_none() {
+            return Ok(());
+        }
+        let mut boundary = coord.boundary();
+        screen.draw(&Polygon::new(boundary.clone(), fill))?;
+        boundary.extend(boundary.first().copied());
+        screen.draw(&PathElement::new(boundary, stroke))?;
+        Ok(())
+    }
+
+    /// Draws the legend of a categorical map, e.g. land-use classes or the classes of a
+    /// choropleth, in the `position` box in backend pixels: a color swatch and the label of
+    /// each entry, under the optional `title`. The entries fill the box column after column,
+    /// taking as many columns as needed to fit its height.
+    pub fn draw_category_legend(
+        &mut self,
+        position: Rect,
+        entries: &[(String, RGBColor)],
+        title: Option<&str>,
+        text_style: &TextStyle,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let screen = self.drawing_area.use_screen_coord();
+        let ((x0, mut y0), (x1, y1)) = position.corners();
+
+        let text_height = |text: &str| {
+            self.drawing_area
+                .estimate_text_size(text, text_style)
+                .map_or(0, |(_, h)| h as i32)
+        };
+        if let Some(title) = title {
+            let pos = Pos::new(HPos::Left, VPos::Top);
+            screen.draw(&Text::new(title.to_string(), (x0, y0), text_style.pos(pos)))?;
+            y0 += text_height(title) + LEGEND_GAP_PX;
+        }
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        let swatch = entries
+            .iter()
+            .map(|(label, _)| text_height(label))
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        let row_height = swatch + LEGEND_GAP_PX;
+        let rows = ((y1 - y0 + LEGEND_GAP_PX) / row_height).max(1) as usize;
+        let columns = (entries.len() + rows - 1) / rows;
+        let column_width = (x1 - x0) / columns as i32;
+
+        let pos = Pos::new(HPos::Left, VPos::Center);
+        for (i, (label, color)) in entries.iter().enumerate() {
+            let x = x0 + (i / rows) as i32 * column_width;
+            let y = y0 + (i % rows) as i32 * row_height;
+            let corners = [(x, y), (x + swatch, y + swatch)];
+            screen.draw(&Rectangle::new(corners, color.filled()))?;
+            let anchor = (x + swatch + LEGEND_GAP_PX, y + swatch / 2);
+            screen.draw(&Text::new(label.clone(), anchor, text_style.pos(pos)))?;
+        }
+        Ok(())
+    }
+}
+
+/// The gap between the rows of a legend and between a swatch and its label
+const LEGEND_GAP_PX: i32 = 4;
+
+/// How the labels of [`ChartContext::draw_frame_ticks`] avoid each other, mostly around the
+/// corners of the map, where the labels of two edges meet
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LabelCollisionPolicy {
+    /// Slide a label overlapping one drawn before along its edge, away from the corner
+    Nudge,
+    /// Leave out a label overlapping one drawn before
+    DropLower,
+    /// Draw every label where it belongs, even over another one
+    AllowOverlap,
+}
+
+/// The pixel box, top-left and bottom-right corners, of a text of `size` anchored at
+/// `anchor` with `pos`
+fn text_box(anchor: BackendCoord, size: (u32, u32), pos: Pos) -> (BackendCoord, BackendCoord) {
+    let (w, h) = (size.0 as i32, size.1 as i32);
+    let x0 = match pos.h_pos {
+        HPos::Left => anchor.0,
+        HPos::Center => anchor.0 - w / 2,
+        HPos::Right => anchor.0 - w,
+    };
+    let y0 = match pos.v_pos {
+        VPos::Top => anchor.1,
+        VPos::Center => anchor.1 - h / 2,
+        VPos::Bottom => anchor.1 - h,
+    };
+    ((x0, y0), (x0 + w, y0 + h))
+}
+
+/// The most times [`ChartContext::draw_markers_with_leaders`] extends the offset of a label
+const MAX_LEADER_STEPS: i32 = 4;
+
+/// Whether the pixel boxes, given by their top-left and bottom-right corners, overlap
+fn boxes_overlap(a: (BackendCoord, BackendCoord), b: (BackendCoord, BackendCoord)) -> bool {
+    a.0 .0 <= b.1 .0 && b.0 .0 <= a.1 .0 && a.0 .1 <= b.1 .1 && b.0 .1 <= a.1 .1
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "proj")]
+    use super::LabelCollisionPolicy;
+    use crate::coord::geo::{Grid2D, PlateCarree};
+    #[cfg(feature = "proj")]
+    use crate::coord::geo::{DistortionMetric, Mercator, Orthographic};
+    #[cfg(feature = "proj")]
+    use crate::drawing::Rect;
+    use crate::prelude::*;
+    #[cfg(feature = "proj")]
+    use plotters_backend::BackendCoord;
+    #[cfg(feature = "proj")]
+    use std::cell::RefCell;
+    #[cfg(feature = "proj")]
+    use std::rc::Rc;
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_draw_frame_ticks() {
+        let drawing_area = create_mocked_drawing_area(600, 400, |m| {
+            // The 30W, 0 and 30E meridians on both horizontal edges, 30N on both sides
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 8);
+                assert_eq!(b.num_draw_text_call, 8);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_geo_coord(
+                Some((-30.0, 30.0)),
+                Some((20.0, 50.0)),
+                Mercator::new().build(),
+            )
+            .unwrap();
+        chart
+            .draw_frame_ticks(
+                30.0,
+                30.0,
+                5,
+                &("sans-serif", 10).into(),
+                LabelCollisionPolicy::AllowOverlap,
+            )
+            .unwrap();
+    }
+
+    /// The anchors of the labels `draw_frame_ticks` draws on a map whose corners are all on
+    /// graticule lines
+    #[cfg(feature = "proj")]
+    fn frame_labels(collisions: LabelCollisionPolicy) -> Vec<(String, BackendCoord)> {
+        let labels = Rc::new(RefCell::new(vec![]));
+        {
+            let labels = labels.clone();
+            let drawing_area = create_mocked_drawing_area(600, 400, move |m| {
+                for _ in 0..12 {
+                    let labels = labels.clone();
+                    m.check_draw_text(move |_, _, text, pos, _| {
+                        labels.borrow_mut().push((text.to_string(), pos))
+                    });
+                }
+            });
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .build_geo_coord(
+                    Some((-30.0, 30.0)),
+                    Some((-30.0, 30.0)),
+                    Mercator::new().build(),
+                )
+                .unwrap();
+            chart
+                .draw_frame_ticks(30.0, 30.0, 5, &("sans-serif", 10).into(), collisions)
+                .unwrap();
+        }
+        let labels = labels.borrow().clone();
+        labels
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_frame_label_collisions() {
+        let overlapping = frame_labels(LabelCollisionPolicy::AllowOverlap);
+        assert_eq!(overlapping.len(), 12);
+
+        // The parallels through the corners lose their labels to the meridians
+        let dropped = frame_labels(LabelCollisionPolicy::DropLower);
+        assert_eq!(dropped.len(), 8);
+        assert_eq!(dropped.iter().filter(|l| l.0 == "0°").count(), 4);
+
+        // They slide along the vertical edges, toward the middle of the map
+        let nudged = frame_labels(LabelCollisionPolicy::Nudge);
+        assert_eq!(nudged.len(), 12);
+        for (label, anchor) in &nudged {
+            let before = overlapping
+                .iter()
+                .find(|(l, a)| l == label && a.0 == anchor.0);
+            let before = before.unwrap().1;
+            match label.as_str() {
+                "30°N" => assert!(anchor.1 > before.1),
+                "30°S" => assert!(anchor.1 < before.1),
+                _ => assert_eq!(*anchor, before),
+            }
+        }
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_draw_globe_disk() {
+        let disk = Rc::new(RefCell::new(vec![]));
+        let drawing_area = {
+            let disk = disk.clone();
+            create_mocked_drawing_area(400, 400, move |m| {
+                m.check_fill_polygon(move |_, path| *disk.borrow_mut() = path);
+                m.drop_check(|b| {
+                    assert_eq!(b.num_fill_polygon_call, 1);
+                    assert_eq!(b.num_draw_path_call, 1);
+                });
+            })
+        };
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_geo_coord(None, None, Orthographic::new().build())
+            .unwrap();
+        chart
+            .draw_globe_disk(BLUE.mix(0.1).filled(), BLACK.into())
+            .unwrap();
+
+        // The disk spans the plotting area, its radius is the projected one in pixels
+        let coord = chart.as_coord_spec();
+        let ((cx, cy), radius) = coord.globe_disk().unwrap();
+        let center = coord.translate(&(0.0, 0.0));
+        let edge = coord.translate(&(90.0, 0.0));
+        let radius_px = f64::from(edge.0 - center.0);
+        assert!((radius_px - 199.5).abs() <= 1.0);
+        assert!(cx.abs() < 1e-6 && cy.abs() < 1e-6);
+        assert!((radius - 6_378_137.0).abs() < 1e-3);
+        for (x, y) in disk.borrow().iter() {
+            let r = f64::from(x - center.0).hypot(f64::from(y - center.1));
+            assert!((r - radius_px).abs() <= 1.5);
+        }
+
+        // Mercator has no disk to draw
+        let drawing_area = create_mocked_drawing_area(400, 400, |m| {
+            m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 0));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_geo_coord(None, Some((-60.0, 60.0)), Mercator::new().build())
+            .unwrap();
+        chart
+            .draw_globe_disk(BLUE.mix(0.1).filled(), BLACK.into())
+            .unwrap();
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_draw_category_legend() {
+        let swatches = Rc::new(RefCell::new(vec![]));
+        let labels = Rc::new(RefCell::new(vec![]));
+        let drawing_area = {
+            let (swatches, labels) = (swatches.clone(), labels.clone());
+            create_mocked_drawing_area(600, 400, move |m| {
+                for _ in 0..6 {
+                    let swatches = swatches.clone();
+                    m.check_draw_rect(move |_, _, _, upper_left, _| {
+                        swatches.borrow_mut().push(upper_left)
+                    });
+                }
+                for _ in 0..7 {
+                    let labels = labels.clone();
+                    m.check_draw_text(move |_, _, text, _, _| {
+                        labels.borrow_mut().push(text.to_string())
+                    });
+                }
+                m.drop_check(|b| {
+                    assert_eq!(b.num_draw_rect_call, 6);
+                    assert_eq!(b.num_draw_text_call, 7);
+                });
+            })
+        };
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_geo_coord(None, None, Mercator::new().build())
+            .unwrap();
+        let classes = ["Forest", "Cropland", "Urban", "Water", "Wetland", "Barren"];
+        let colors = [GREEN, YELLOW, RED, BLUE, CYAN, BLACK];
+        let entries: Vec<_> = classes
+            .iter()
+            .zip(colors.iter())
+            .map(|(class, color)| (class.to_string(), *color))
+            .collect();
+        // Room for the title and three rows
+        let style: TextStyle = ("sans-serif", 10).into();
+        let height = |text: &str| {
+            let size = chart.drawing_area.estimate_text_size(text, &style);
+            size.map_or(0, |(_, h)| h as i32)
+        };
+        let swatch = classes.iter().map(|c| height(c)).max().unwrap().max(1);
+        let bottom = 10 + height("Land use") + 3 * (swatch + 4) + 2;
+        let position = Rect::new((10, 10), (210, bottom));
+        chart
+            .draw_category_legend(position, &entries, Some("Land use"), &style)
+            .unwrap();
+
+        let mut expected = vec!["Land use".to_string()];
+        expected.extend(classes.iter().map(|c| c.to_string()));
+        assert_eq!(*labels.borrow(), expected);
+        // Two columns of three swatches
+        let swatches = swatches.borrow();
+        assert_eq!(swatches[0].0, 10);
+        assert_eq!(swatches[2].0, 10);
+        assert_eq!(swatches[3], (110, swatches[0].1));
+        assert!(swatches[1].1 > swatches[0].1);
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_draw_markers_with_leaders() {
+        let leaders = Rc::new(RefCell::new(vec![]));
+        let labels = Rc::new(RefCell::new(vec![]));
+        let drawing_area = {
+            let (leaders, labels) = (leaders.clone(), labels.clone());
+            create_mocked_drawing_area(600, 400, move |m| {
+                m.check_draw_path(move |_, _, path| leaders.borrow_mut().push(path));
+                m.check_draw_text(move |_, _, _, pos, _| labels.borrow_mut().push(pos));
+            })
+        };
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_geo_coord(
+                Some((-10.0, 10.0)),
+                Some((40.0, 55.0)),
+                Mercator::new().build(),
+            )
+            .unwrap();
+        let paris = (2.35, 48.85);
+        let markers = [
+            (paris, "Paris".to_string()),
+            (paris, "Paris again".to_string()),
+            ((-50.0, 0.0), "Off the map".to_string()),
+        ];
+        chart
+            .draw_markers_with_leaders(
+                &markers,
+                (30, -30),
+                BLACK.into(),
+                &("sans-serif", 10).into(),
+            )
+            .unwrap();
+
+        let marker = chart.as_coord_spec().translate(&paris);
+        let (leaders, labels) = (leaders.borrow(), labels.borrow());
+        assert_eq!(leaders.len(), 2);
+        // Each leader goes from the marker to the anchor of its label
+        for (leader, label) in leaders.iter().zip(labels.iter()) {
+            assert_eq!(leader[0], marker);
+            assert_eq!(leader[1], *label);
+        }
+        // The overlapping label is pushed further away
+        assert_eq!(labels[0], (marker.0 + 30, marker.1 - 30));
+        assert_eq!(labels[1], (marker.0 + 60, marker.1 - 60));
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn test_draw_terminator() {
+        use chrono::{TimeZone, Utc};
+
+        let drawing_area = create_mocked_drawing_area(600, 400, |m| {
+            m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 1));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_geo_coord(None, None, PlateCarree::geographic().build())
+            .unwrap();
+        let noon = Utc.with_ymd_and_hms(2024, 3, 20, 12, 0, 0).unwrap();
+        chart
+            .draw_terminator(noon, BLACK.mix(0.4).filled())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_draw_gridded_field() {
+        let drawing_area = create_mocked_drawing_area(101, 101, |m| {
+            m.check_draw_rect(|color, _, filled, upper_left, bottom_right| {
+                assert!(filled);
+                // The middle of the west-east ramp from 0 to 1
+                if upper_left == (50, 50) && bottom_right == (50, 50) {
+                    assert_eq!(color, RGBColor(127, 0, 0).to_rgba());
+                }
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 101 * 101 + 10 * 10));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_geo_coord(
+                Some((-10.0, 10.0)),
+                Some((-10.0, 10.0)),
+                PlateCarree::geographic().build(),
+            )
+            .unwrap();
+        let ramp = Grid2D::new(2, vec![0.0, 1.0, 0.0, 1.0]).unwrap();
+        let bounds = ((-10.0, 10.0), (-10.0, 10.0));
+        let gradient = |v: f64| RGBColor((255.0 * v) as u8, 0, 0);
+        chart
+            .draw_gridded_field(&ramp, bounds, gradient, 1)
+            .unwrap();
+        // The last blocks of each row and column are centered off the map
+        chart
+            .draw_gridded_field(&ramp, bounds, gradient, 10)
+            .unwrap();
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_draw_distortion_overlay() {
+        let drawing_area = create_mocked_drawing_area(600, 400, |m| {
+            // 4 x 3 cells of 15 degrees
+            m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 12));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_geo_coord(
+                Some((-30.0, 30.0)),
+                Some((0.0, 45.0)),
+                Mercator::new().build(),
+            )
+            .unwrap();
+        chart
+            .draw_distortion_overlay(
+                DistortionMetric::Area,
+                |v| RGBColor((255.0 * (v - 1.0).min(1.0)) as u8, 0, 0),
+                15.0,
+            )
+            .unwrap();
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_draw_polygons_styled() {
+        let drawing_area = create_mocked_drawing_area(400, 300, |m| {
+            m.check_fill_polygon(|color, _| assert_eq!(color, RED.to_rgba()));
+            m.check_fill_polygon(|color, _| assert_eq!(color, BLUE.to_rgba()));
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 2);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_geo_coord(
+                Some((-10.0, 10.0)),
+                Some((40.0, 60.0)),
+                Mercator::new().build(),
+            )
+            .unwrap();
+
+        let triangle = |lon: f64, lat: f64| vec![(lon, lat), (lon + 2.0, lat), (lon, lat + 2.0)];
+        let features = vec![
+            (vec![triangle(-5.0, 45.0)], 5_000_000),
+            (vec![triangle(3.0, 50.0)], 200_000),
+            (vec![triangle(0.0, 55.0)], 0),
+        ];
+        chart
+            .draw_polygons_styled(&features, |&population| match population {
+                0 => BLACK.into(),
+                p if p > 1_000_000 => RED.filled(),
+                _ => BLUE.filled(),
+            })
+            .unwrap();
+    }
+}
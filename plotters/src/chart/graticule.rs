@@ -0,0 +1,275 @@
+use super::context::ChartContext;
+use crate::coord::geo::{graticule_values, LatLonCoord, ProjectionS};
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::{Circle, PathElement};
+use crate::style::{Color, RGBColor, ShapeStyle};
+
+use plotters_backend::{BackendCoord, DrawingBackend};
+
+/// The style used to describe the graticule, i.e. the meridians and parallels, of a
+/// geographic chart.
+pub struct GraticuleStyle<'a, 'b, DB: DrawingBackend, T: ProjectionS> {
+    pub(super) lon_step: f64,
+    pub(super) lat_step: f64,
+    pub(super) line_style: Option<ShapeStyle>,
+    pub(super) dash: Option<Vec<f64>>,
+    pub(super) clip: Option<Vec<(f64, f64)>>,
+    pub(super) target: Option<&'b mut ChartContext<'a, DB, LatLonCoord<T>>>,
+}
+
+impl<'a, 'b, DB: DrawingBackend, T: ProjectionS> GraticuleStyle<'a, 'b, DB, T> {
+    pub(crate) fn new(chart: &'b mut ChartContext<'a, DB, LatLonCoord<T>>) -> Self {
+        Self {
+            lon_step: 30.0,
+            lat_step: 30.0,
+            line_style: None,
+            dash: None,
+            clip: None,
+            target: Some(chart),
+        }
+    }
+
+    /// Set the spacing between two meridians
+    /// - `step`: The spacing in degrees
+    pub fn lon_step(&mut self, step: f64) -> &mut Self {
+        self.lon_step = step;
+        self
+    }
+
+    /// Set the spacing between two parallels
+    /// - `step`: The spacing in degrees
+    pub fn lat_step(&mut self, step: f64) -> &mut Self {
+        self.lat_step = step;
+        self
+    }
+
+    /// Set the style of the graticule lines
+    pub fn line_style<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+        self.line_style = Some(style.into());
+        self
+    }
+
+    /// Draw the graticule lines dashed instead of solid
+    /// - `pattern`: The alternating lengths, in pixels, of the dashes and the gaps between them
+    pub fn dash(&mut self, pattern: Vec<f64>) -> &mut Self {
+        self.dash = Some(pattern);
+        self
+    }
+
+    /// Only draw the graticule within a geographic polygon, e.g. a country boundary
+    /// - `polygon`: The `(lon, lat)` ring to clip the graticule lines to
+    pub fn clip(&mut self, polygon: &[(f64, f64)]) -> &mut Self {
+        self.clip = Some(polygon.to_vec());
+        self
+    }
+
+    /// Draw the configured graticule on the target plot
+    pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let target = self.target.take().unwrap();
+
+        let style = self
+            .line_style
+            .unwrap_or_else(|| RGBColor(0, 0, 0).mix(0.2).into());
+
+        let coord = target.drawing_area.as_coord_spec();
+        let area = target.drawing_area.use_screen_coord();
+        let lines = match (&self.clip, &self.dash) {
+            (Some(polygon), _) => {
+                coord.graticule_lines_within(self.lon_step, self.lat_step, polygon)
+            }
+            (None, Some(_)) => coord.graticule_lines(self.lon_step, self.lat_step),
+            // Parallels that are circles on screen are drawn as such
+            (None, None) => {
+                let (lon, lat) = coord.extent();
+                let mut lines: Vec<_> = graticule_values(lon, self.lon_step)
+                    .map(|v| coord.meridian_line(v))
+                    .collect();
+                for v in graticule_values(lat, self.lat_step) {
+                    match coord.parallel_circle(v) {
+                        Some((center, radius)) => {
+                            area.draw(&Circle::new(center, radius, style))?;
+                        }
+                        None => lines.push(coord.parallel_line(v)),
+                    }
+                }
+                lines.retain(|line| line.len() > 1);
+                lines
+            }
+        };
+        for line in lines {
+            let pieces = match self.dash {
+                Some(ref pattern) => dash_polyline(&line, pattern),
+                None => vec![line],
+            };
+            for piece in pieces {
+                area.draw(&PathElement::new(piece, style))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Splits the polyline into the pieces covered by the dashes of `pattern`. The dash phase
+/// carries over from one segment to the next, so dashes don't restart at every vertex.
+fn dash_polyline(points: &[BackendCoord], pattern: &[f64]) -> Vec<Vec<BackendCoord>> {
+    if points.is_empty() || pattern.iter().any(|v| *v < 0.0) || pattern.iter().sum::<f64>() <= 0.0 {
+        return vec![points.to_vec()];
+    }
+
+    let mut pieces = vec![];
+    let mut current = vec![points[0]];
+    let (mut idx, mut on) = (0, true);
+    let mut left = pattern[0];
+
+    for w in points.windows(2) {
+        let (a, b) = (w[0], w[1]);
+        let (dx, dy) = (f64::from(b.0 - a.0), f64::from(b.1 - a.1));
+        let len = dx.hypot(dy);
+        let mut pos = 0.0;
+        while len - pos > left {
+            pos += left;
+            let p = (
+                a.0 + (dx * pos / len).round() as i32,
+                a.1 + (dy * pos / len).round() as i32,
+            );
+            if on {
+                current.push(p);
+                pieces.push(std::mem::take(&mut current));
+            } else {
+                current = vec![p];
+            }
+            on = !on;
+            idx = (idx + 1) % pattern.len();
+            left = pattern[idx];
+        }
+        left -= len - pos;
+        if on {
+            current.push(b);
+        }
+    }
+    if on && current.len() > 1 {
+        pieces.push(current);
+    }
+    pieces
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "proj")]
+    use crate::coord::geo::{Mercator, PolarStereographic};
+    #[cfg(feature = "proj")]
+    use crate::prelude::*;
+    #[cfg(feature = "proj")]
+    use std::cell::{Cell, RefCell};
+    #[cfg(feature = "proj")]
+    use std::rc::Rc;
+
+    #[test]
+    fn test_dash_polyline() {
+        let pieces = dash_polyline(&[(0, 0), (15, 0), (15, 15)], &[10.0, 5.0]);
+        assert_eq!(pieces, vec![vec![(0, 0), (10, 0)], vec![(15, 0), (15, 10)]]);
+    }
+
+    #[cfg(feature = "proj")]
+    fn count_graticule_paths(dash: Option<Vec<f64>>) -> u32 {
+        let count = Rc::new(Cell::new(0));
+        {
+            let count = count.clone();
+            let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+                m.drop_check(move |b| count.set(b.num_draw_path_call));
+            });
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .build_geo_coord(
+                    Some((-5.0, 5.0)),
+                    Some((-40.0, 40.0)),
+                    Mercator::new().build(),
+                )
+                .unwrap();
+            let mut graticule = chart.configure_graticule();
+            graticule.lon_step(10.0).lat_step(100.0);
+            if let Some(pattern) = dash {
+                graticule.dash(pattern);
+            }
+            graticule.draw().unwrap();
+        }
+        count.get()
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_clipped_graticule() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            // 3 meridians east of the prime meridian and 5 half parallels
+            for _ in 0..8 {
+                m.check_draw_path(|_, _, path| assert!(path.iter().all(|p| p.0 >= 99)));
+            }
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 8));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_geo_coord(
+                Some((-20.0, 20.0)),
+                Some((-20.0, 20.0)),
+                Mercator::new().build(),
+            )
+            .unwrap();
+        chart
+            .configure_graticule()
+            .lon_step(10.0)
+            .lat_step(10.0)
+            .clip(&[(0.0, -30.0), (30.0, -30.0), (30.0, 30.0), (0.0, 30.0)])
+            .draw()
+            .unwrap();
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_circular_parallels() {
+        let circles = Rc::new(RefCell::new(vec![]));
+        let drawing_area = create_mocked_drawing_area(300, 300, {
+            let circles = circles.clone();
+            move |m| {
+                m.check_draw_circle(move |_, _, filled, center, radius| {
+                    assert!(!filled);
+                    circles.borrow_mut().push((center, radius));
+                });
+                m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 1));
+            }
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_geo_coord(
+                None,
+                Some((40.0, 90.0)),
+                PolarStereographic::north().build(),
+            )
+            .unwrap();
+        chart
+            .configure_graticule()
+            .lon_step(30.0)
+            .lat_step(30.0)
+            .draw()
+            .unwrap();
+
+        // The 60th parallel, the pole being a point
+        let coord = chart.plotting_area().as_coord_spec();
+        let pole = coord.translate(&(0.0, 90.0));
+        let (center, radius) = circles.borrow()[0];
+        assert!((center.0 - pole.0).abs() <= 1 && (center.1 - pole.1).abs() <= 1);
+        for lon in [0.0, 45.0, 135.0].iter() {
+            let p = coord.translate(&(*lon, 60.0));
+            let distance = f64::from((p.0 - pole.0).pow(2) + (p.1 - pole.1).pow(2)).sqrt();
+            assert!((distance - f64::from(radius)).abs() <= 1.5);
+        }
+        assert!(coord.parallel_circle(40.0).is_some());
+        assert_eq!(coord.parallel_circle(90.0), None);
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_dashed_graticule() {
+        let solid = count_graticule_paths(None);
+        let dashed = count_graticule_paths(Some(vec![4.0, 4.0]));
+        assert_eq!(solid, 2);
+        assert!(dashed > 10 * solid);
+    }
+}
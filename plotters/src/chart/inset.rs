@@ -0,0 +1,134 @@
+use crate::coord::geo::{LatLonCoord, ProjectionS};
+use crate::coord::{CoordTranslate, Shift};
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::element::PathElement;
+use crate::style::ShapeStyle;
+
+use plotters_backend::{BackendCoord, DrawingBackend};
+
+/// The number of points each edge of the inset extent is sampled with on the main map, so
+/// the indicator follows the parallels and meridians of curved projections
+const INDICATOR_EDGE_SAMPLES: usize = 16;
+
+/**
+A small map drawn within a figure next to the main map, e.g. a zoomed region or a globe
+locator, with its own extent and projection.
+
+The inset can point out its extent on the main map with an indicator drawn by
+[`draw_indicator`](Self::draw_indicator).
+
+```rust
+use plotters::prelude::*;
+use plotters::chart::InsetMap;
+use plotters::coord::geo::{Mercator, Orthographic};
+
+let root = SVGBackend::new("inset.svg", (600, 400)).into_drawing_area();
+let main = root.apply_geo_coord(None, Some((-60.0, 70.0)), Mercator::new().build());
+
+let corner = root.clone().shrink((450, 250), (140, 140));
+let inset = InsetMap::new(
+    &corner,
+    Some((-10.0, 30.0)),
+    Some((35.0, 60.0)),
+    Orthographic::new().center(10.0, 47.0).build(),
+);
+inset.draw_indicator(&main, RED.into()).unwrap();
+inset.area().draw(&Circle::new((2.35, 48.85), 3, RED.filled())).unwrap();
+```
+*/
+pub struct InsetMap<DB: DrawingBackend, T: ProjectionS> {
+    area: DrawingArea<DB, LatLonCoord<T>>,
+}
+
+impl<DB: DrawingBackend, T: ProjectionS> InsetMap<DB, T> {
+    /// Applies a map of the `lon`/`lat` extent, drawn with `proj`, to the whole `area`,
+    /// usually a part of the main map's area. `None` ranges fall back to the projection's
+    /// natural extent.
+    pub fn new(
+        area: &DrawingArea<DB, Shift>,
+        lon: Option<(f64, f64)>,
+        lat: Option<(f64, f64)>,
+        proj: T,
+    ) -> Self {
+        Self {
+            area: area.apply_geo_coord(lon, lat, proj),
+        }
+    }
+
+    /// The drawing area of the inset, to draw its own series on
+    pub fn area(&self) -> &DrawingArea<DB, LatLonCoord<T>> {
+        &self.area
+    }
+
+    /// Returns the closed ring, in backend pixels, of the extent of the inset on the `main`
+    /// map. Points of the extent that `main` can't project are left out.
+    pub fn indicator<M: ProjectionS>(&self, main: &LatLonCoord<M>) -> Vec<BackendCoord> {
+        let ((w, e), (s, n)) = self.area.as_coord_spec().extent();
+        let corners = [(w, s), (e, s), (e, n), (w, n), (w, s)];
+        let mut ring: Vec<_> = corners
+            .windows(2)
+            .flat_map(|edge| {
+                let (a, b) = (edge[0], edge[1]);
+                (0..INDICATOR_EDGE_SAMPLES).map(move |i| {
+                    let t = i as f64 / INDICATOR_EDGE_SAMPLES as f64;
+                    (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t)
+                })
+            })
+            .filter(|p| main.is_visible(*p))
+            .map(|p| main.translate(&p))
+            .collect();
+        ring.extend(ring.first().copied());
+        ring
+    }
+
+    /// Strokes the [indicator](Self::indicator) of the inset extent on the `main` map
+    pub fn draw_indicator<M: ProjectionS>(
+        &self,
+        main: &DrawingArea<DB, LatLonCoord<M>>,
+        style: ShapeStyle,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let ring = self.indicator(main.as_coord_spec());
+        main.use_screen_coord().draw(&PathElement::new(ring, style))
+    }
+}
+
+#[cfg(all(test, feature = "proj"))]
+mod test {
+    use super::*;
+    use crate::coord::geo::{Orthographic, PlateCarree};
+    use crate::prelude::*;
+
+    #[test]
+    fn test_inset_map() {
+        let drawing_area = create_mocked_drawing_area(361, 181, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_circle_call, 1);
+            });
+        });
+        let main = drawing_area.apply_geo_coord(None, None, PlateCarree::geographic().build());
+        let corner = drawing_area.clone().shrink((261, 81), (100, 100));
+        let inset = InsetMap::new(
+            &corner,
+            Some((0.0, 30.0)),
+            Some((30.0, 60.0)),
+            Orthographic::new().center(15.0, 45.0).build(),
+        );
+
+        // One degree per pixel on the main map, north up from the top-left corner
+        let ring = inset.indicator(main.as_coord_spec());
+        let xs = ring.iter().map(|p| p.0);
+        let ys = ring.iter().map(|p| p.1);
+        assert_eq!((xs.clone().min(), xs.max()), (Some(180), Some(210)));
+        assert_eq!((ys.clone().min(), ys.max()), (Some(30), Some(60)));
+        assert_eq!(ring.first(), ring.last());
+
+        inset.draw_indicator(&main, RED.into()).unwrap();
+        let center = inset.area().as_coord_spec().translate(&(15.0, 45.0));
+        assert!((center.0 - 311).abs() <= 1 && (center.1 - 131).abs() <= 1);
+        inset
+            .area()
+            .draw(&Circle::new((15.0, 45.0), 3, RED))
+            .unwrap();
+    }
+}
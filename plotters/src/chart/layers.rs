@@ -0,0 +1,142 @@
+use super::GeoLines;
+use crate::coord::geo::{LatLonCoord, ProjectionS};
+use crate::element::PathElement;
+use crate::style::ShapeStyle;
+
+use plotters_backend::BackendCoord;
+
+/**
+Several layers of `(lon, lat)` polylines projected together on one map.
+
+Drawing each overlay with its own series projects its points one by one. A layer group
+collects the points of all its layers and projects them with a single
+[`translate_many`](LatLonCoord::translate_many) call, which goes into PROJ once, then hands
+back the drawable paths of each layer. The points are projected as given, without the
+densification and clipping of [`project_path`](LatLonCoord::project_path).
+
+```rust
+use plotters::prelude::*;
+use plotters::chart::GeoLayerGroup;
+use plotters::coord::geo::Mercator;
+
+let area = SVGBackend::new("layers.svg", (400, 300)).into_drawing_area();
+let chart = ChartBuilder::on(&area)
+    .build_geo_coord(Some((-10.0, 10.0)), Some((40.0, 60.0)), Mercator::new().build())
+    .unwrap();
+
+let mut group = GeoLayerGroup::new(chart.as_coord_spec());
+group
+    .layer(vec![vec![(-5.0, 48.0), (2.0, 51.0)]], RED)
+    .layer(vec![vec![(0.0, 45.0), (5.0, 45.0), (8.0, 50.0)]], BLUE);
+let screen = chart.plotting_area().use_screen_coord();
+for layer in group.project() {
+    for path in layer {
+        screen.draw(&path).unwrap();
+    }
+}
+```
+*/
+pub struct GeoLayerGroup<'c, T: ProjectionS> {
+    coord: &'c LatLonCoord<T>,
+    layers: Vec<(GeoLines, ShapeStyle)>,
+}
+
+impl<'c, T: ProjectionS> GeoLayerGroup<'c, T> {
+    /// Creates an empty group of layers on the map `coord`
+    pub fn new(coord: &'c LatLonCoord<T>) -> Self {
+        Self {
+            coord,
+            layers: vec![],
+        }
+    }
+
+    /// Adds a layer of `(lon, lat)` polylines drawn with `style`
+    pub fn layer<S: Into<ShapeStyle>>(
+        &mut self,
+        lines: Vec<Vec<(f64, f64)>>,
+        style: S,
+    ) -> &mut Self {
+        self.layers.push((lines, style.into()));
+        self
+    }
+
+    /// Projects the points of every layer at once, and returns the paths of each layer in
+    /// backend pixels, in the order the layers were added. A line is split where its points
+    /// can't be projected, and the pieces shorter than two points are left out.
+    pub fn project(&self) -> Vec<Vec<PathElement<BackendCoord>>> {
+        let points: Vec<_> = self
+            .layers
+            .iter()
+            .flat_map(|(lines, _)| lines.iter().flatten().copied())
+            .collect();
+        let mut pixels = self.coord.translate_many(&points).into_iter();
+
+        self.layers
+            .iter()
+            .map(|(lines, style)| {
+                let mut paths = vec![];
+                for line in lines {
+                    let mut piece = vec![];
+                    for pixel in pixels.by_ref().take(line.len()) {
+                        match pixel {
+                            Some(p) => piece.push(p),
+                            None => paths.extend(finish(&mut piece, *style)),
+                        }
+                    }
+                    paths.extend(finish(&mut piece, *style));
+                }
+                paths
+            })
+            .collect()
+    }
+}
+
+/// Takes the points of `piece` as a path, if they make a line
+fn finish(piece: &mut Vec<BackendCoord>, style: ShapeStyle) -> Option<PathElement<BackendCoord>> {
+    let points = std::mem::take(piece);
+    if points.len() > 1 {
+        Some(PathElement::new(points, style))
+    } else {
+        None
+    }
+}
+
+#[cfg(all(test, feature = "proj"))]
+mod test {
+    use super::*;
+    use crate::coord::geo::Orthographic;
+    use crate::element::PointCollection;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_geo_layer_group() {
+        let coord = LatLonCoord::new(None, None, (0..400, 400..0), Orthographic::new().build());
+        let rivers = vec![vec![(0.0, 0.0), (10.0, 10.0), (20.0, 5.0)]];
+        // The middle point is on the far side of the globe
+        let roads = vec![
+            vec![(-30.0, 0.0), (150.0, 0.0), (30.0, 0.0), (40.0, 10.0)],
+            vec![(5.0, 5.0)],
+        ];
+
+        let mut group = GeoLayerGroup::new(&coord);
+        group.layer(rivers.clone(), BLUE).layer(roads, RED);
+        let layers = group.project();
+        assert_eq!(layers.len(), 2);
+
+        let points = |path: &PathElement<BackendCoord>| -> Vec<BackendCoord> {
+            path.point_iter().into_iter().copied().collect()
+        };
+        let river = points(&layers[0][0]);
+        let expected: Vec<_> = rivers[0].iter().map(|p| coord.translate(p)).collect();
+        assert_eq!(river, expected);
+
+        assert_eq!(layers[1].len(), 1);
+        assert_eq!(
+            points(&layers[1][0]),
+            vec![
+                coord.translate(&(30.0, 0.0)),
+                coord.translate(&(40.0, 10.0))
+            ]
+        );
+    }
+}
@@ -13,18 +13,32 @@ detailed description for each struct.
 */
 
 mod axes3d;
+mod basemap;
 mod builder;
 mod context;
 mod dual_coord;
+mod graticule;
+mod inset;
+mod layers;
 mod mesh;
+mod renderer;
 mod series;
 mod state;
 
+pub use basemap::{draw_basemap, BasemapStyle};
 pub use builder::{ChartBuilder, LabelAreaPosition};
+pub use context::geo::LabelCollisionPolicy;
 pub use context::ChartContext;
 pub use dual_coord::{DualCoordChartContext, DualCoordChartState};
+pub use graticule::GraticuleStyle;
+pub use inset::InsetMap;
+pub use layers::GeoLayerGroup;
 pub use mesh::{MeshStyle, SecondaryMeshStyle};
+pub use renderer::{GeoRenderer, RenderState};
 pub use series::{SeriesAnno, SeriesLabelPosition, SeriesLabelStyle};
 pub use state::ChartState;
 
 use context::Coord3D;
+
+/// The `(lon, lat)` polylines of a map layer
+pub(crate) type GeoLines = Vec<Vec<(f64, f64)>>;
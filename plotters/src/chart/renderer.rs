@@ -0,0 +1,240 @@
+use super::context::ChartContext;
+use super::GeoLines;
+use crate::coord::geo::{LatLonCoord, ProjectionS};
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::{PathElement, Polygon};
+use crate::style::ShapeStyle;
+
+use plotters_backend::DrawingBackend;
+
+/// Whether a [`GeoRenderer`] has more to draw
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RenderState {
+    /// Some layers or chunks are left, call [`GeoRenderer::step`] again
+    InProgress,
+    /// The whole map is drawn
+    Done,
+}
+
+/// The next thing a [`GeoRenderer`] draws
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Stage {
+    Background,
+    Graticule,
+    Layer { index: usize, offset: usize },
+    Done,
+}
+
+/**
+Draws a map over several calls, so a GUI event loop can render it across frames.
+
+Each call to [`step`](Self::step) draws one piece of the map: the background, then the
+graticule, then each data layer by chunks of [`chunk_size`](Self::chunk_size) lines. The
+drawing is the same as drawing all the pieces at once.
+
+```rust
+use plotters::prelude::*;
+use plotters::chart::{GeoRenderer, RenderState};
+use plotters::coord::geo::Mercator;
+
+let area = SVGBackend::new("geo_renderer.svg", (400, 300)).into_drawing_area();
+let chart = ChartBuilder::on(&area)
+    .build_geo_coord(Some((-10.0, 10.0)), Some((40.0, 60.0)), Mercator::new().build())
+    .unwrap();
+
+let mut renderer = GeoRenderer::new(&chart);
+renderer
+    .background(WHITE.filled())
+    .graticule(5.0, 5.0, BLACK.mix(0.2).into())
+    .layer(vec![vec![(-5.0, 48.0), (2.0, 51.0)]], RED.into());
+// Once per frame
+while renderer.step().unwrap() == RenderState::InProgress {}
+```
+*/
+pub struct GeoRenderer<'a, 'b, DB: DrawingBackend, T: ProjectionS> {
+    chart: &'b ChartContext<'a, DB, LatLonCoord<T>>,
+    background: Option<ShapeStyle>,
+    graticule: Option<(f64, f64, ShapeStyle)>,
+    layers: Vec<(GeoLines, ShapeStyle)>,
+    chunk_size: usize,
+    stage: Stage,
+}
+
+impl<'a, 'b, DB: DrawingBackend, T: ProjectionS> GeoRenderer<'a, 'b, DB, T> {
+    /// Creates a renderer drawing nothing yet on the map of `chart`
+    pub fn new(chart: &'b ChartContext<'a, DB, LatLonCoord<T>>) -> Self {
+        Self {
+            chart,
+            background: None,
+            graticule: None,
+            layers: vec![],
+            chunk_size: 256,
+            stage: Stage::Background,
+        }
+    }
+
+    /// Fill the boundary of the map first
+    pub fn background(&mut self, style: ShapeStyle) -> &mut Self {
+        self.background = Some(style);
+        self
+    }
+
+    /// Draw the graticule after the background
+    /// - `lon_step`: The spacing between two meridians, in degrees
+    /// - `lat_step`: The spacing between two parallels, in degrees
+    pub fn graticule(&mut self, lon_step: f64, lat_step: f64, style: ShapeStyle) -> &mut Self {
+        self.graticule = Some((lon_step, lat_step, style));
+        self
+    }
+
+    /// Add a data layer of `(lon, lat)` polylines, drawn after the previous layers
+    pub fn layer(&mut self, lines: Vec<Vec<(f64, f64)>>, style: ShapeStyle) -> &mut Self {
+        self.layers.push((lines, style));
+        self
+    }
+
+    /// Set how many lines of a data layer are drawn per step, defaults to 256
+    pub fn chunk_size(&mut self, lines: usize) -> &mut Self {
+        self.chunk_size = lines.max(1);
+        self
+    }
+
+    /// Draws the next piece of the map and tells whether anything is left
+    pub fn step(&mut self) -> Result<RenderState, DrawingAreaErrorKind<DB::ErrorType>> {
+        let coord = self.chart.drawing_area.as_coord_spec();
+        let screen = self.chart.drawing_area.use_screen_coord();
+
+        match self.stage {
+            Stage::Background => {
+                if let Some(style) = self.background {
+                    screen.draw(&Polygon::new(coord.boundary(), style))?;
+                }
+                self.stage = Stage::Graticule;
+            }
+            Stage::Graticule => {
+                if let Some((lon_step, lat_step, style)) = self.graticule {
+                    for line in coord.graticule_lines(lon_step, lat_step) {
+                        screen.draw(&PathElement::new(line, style))?;
+                    }
+                }
+                self.stage = self.layer_stage(0);
+            }
+            Stage::Layer { index, offset } => {
+                let (lines, style) = &self.layers[index];
+                let end = (offset + self.chunk_size).min(lines.len());
+                for line in &lines[offset..end] {
+                    for path in coord.project_path(line, *style) {
+                        screen.draw(&path)?;
+                    }
+                }
+                self.stage = if end < lines.len() {
+                    Stage::Layer { index, offset: end }
+                } else {
+                    self.layer_stage(index + 1)
+                };
+            }
+            Stage::Done => {}
+        }
+
+        Ok(match self.stage {
+            Stage::Done => RenderState::Done,
+            _ => RenderState::InProgress,
+        })
+    }
+
+    /// The stage drawing the layer at `index` from its start, if any
+    fn layer_stage(&self, index: usize) -> Stage {
+        if index < self.layers.len() {
+            Stage::Layer { index, offset: 0 }
+        } else {
+            Stage::Done
+        }
+    }
+
+    /// Draws everything left in one go
+    pub fn render(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        while self.step()? == RenderState::InProgress {}
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "proj"))]
+mod test {
+    use super::*;
+    use crate::coord::geo::Mercator;
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// The draw calls of `draw` on a mocked map, as `(fills, paths)`
+    fn record<F>(draw: F) -> Vec<(u32, u32)>
+    where
+        F: FnOnce(&ChartContext<MockedBackend, LatLonCoord<Mercator>>),
+    {
+        let calls = Rc::new(RefCell::new(vec![]));
+        {
+            let calls = calls.clone();
+            let drawing_area = create_mocked_drawing_area(400, 300, move |m| {
+                m.drop_check(move |b| {
+                    calls
+                        .borrow_mut()
+                        .push((b.num_fill_polygon_call, b.num_draw_path_call))
+                });
+            });
+            let chart = ChartBuilder::on(&drawing_area)
+                .build_geo_coord(
+                    Some((-10.0, 10.0)),
+                    Some((40.0, 60.0)),
+                    Mercator::new().build(),
+                )
+                .unwrap();
+            draw(&chart);
+        }
+        let calls = calls.borrow().clone();
+        calls
+    }
+
+    fn coastline(i: usize) -> Vec<(f64, f64)> {
+        let lon = -9.0 + i as f64;
+        vec![(lon, 45.0), (lon + 0.5, 50.0)]
+    }
+
+    #[test]
+    fn test_geo_renderer() {
+        let stepped = record(|chart| {
+            let mut renderer = GeoRenderer::new(chart);
+            renderer
+                .background(WHITE.filled())
+                .graticule(5.0, 5.0, BLACK.into())
+                .layer((0..10).map(coastline).collect(), RED.into())
+                .layer(vec![coastline(3)], BLUE.into())
+                .chunk_size(4);
+            let mut steps = 1;
+            while renderer.step().unwrap() == RenderState::InProgress {
+                steps += 1;
+            }
+            // Background, graticule, 3 chunks and 1 chunk
+            assert_eq!(steps, 6);
+            assert_eq!(renderer.step().unwrap(), RenderState::Done);
+        });
+
+        let one_shot = record(|chart| {
+            let coord = chart.plotting_area().as_coord_spec();
+            let screen = chart.plotting_area().use_screen_coord();
+            screen
+                .draw(&Polygon::new(coord.boundary(), WHITE.filled()))
+                .unwrap();
+            for line in coord.graticule_lines(5.0, 5.0) {
+                screen.draw(&PathElement::new(line, BLACK)).unwrap();
+            }
+            for line in (0..10).map(coastline).chain(Some(coastline(3))) {
+                for path in coord.project_path(&line, RED) {
+                    screen.draw(&path).unwrap();
+                }
+            }
+        });
+
+        assert_eq!(stepped, one_shot);
+        assert!(stepped[0].1 > 10);
+    }
+}
@@ -46,6 +46,11 @@ mod ranged2d;
 /// Ranged coordinates in 3d.
 pub mod ranged3d;
 
+/// Geographic coordinates, mapping longitude/latitude through a map projection.
+pub mod geo {
+    pub use super::ranged2d::geo::*;
+}
+
 /// Groups Cartesian ranged coordinates in 2d and 3d.
 pub mod cartesian {
     pub use super::ranged2d::cartesian::{Cartesian2d, MeshLine};
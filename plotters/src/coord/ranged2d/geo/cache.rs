@@ -0,0 +1,241 @@
+use super::{LatLonCoord, ProjectionS, Range};
+use plotters_backend::BackendCoord;
+
+/// Points whose projections tell projections apart, to notice a changed projection
+const PROBES: [Range; 3] = [(0.0, 0.0), (37.0, 23.0), (-121.0, -47.0)];
+
+/// A tile of the quadtree, with the lines lying within it and their cached projections
+struct Tile {
+    bounds: (Range, Range),
+    lines: Vec<Vec<Range>>,
+    projected: Option<Vec<Vec<Range>>>,
+    children: Vec<Tile>,
+}
+
+impl Tile {
+    fn new(bounds: (Range, Range)) -> Self {
+        Self {
+            bounds,
+            lines: vec![],
+            projected: None,
+            children: vec![],
+        }
+    }
+
+    fn insert(&mut self, line: Vec<Range>, bbox: (Range, Range), depth: u32) {
+        if depth > 0 {
+            if self.children.is_empty() {
+                let ((w, e), (s, n)) = self.bounds;
+                let (lon, lat) = ((w + e) / 2.0, (s + n) / 2.0);
+                self.children = vec![
+                    Tile::new(((w, lon), (s, lat))),
+                    Tile::new(((lon, e), (s, lat))),
+                    Tile::new(((w, lon), (lat, n))),
+                    Tile::new(((lon, e), (lat, n))),
+                ];
+            }
+            if let Some(child) = self.children.iter_mut().find(|c| contains(c.bounds, bbox)) {
+                return child.insert(line, bbox, depth - 1);
+            }
+        }
+        self.lines.push(line);
+        self.projected = None;
+    }
+
+    fn invalidate(&mut self) {
+        self.projected = None;
+        self.children.iter_mut().for_each(Tile::invalidate);
+    }
+
+    fn collect<T: ProjectionS>(
+        &mut self,
+        coord: &LatLonCoord<T>,
+        visible: (Range, Range),
+        output: &mut Vec<Vec<BackendCoord>>,
+    ) {
+        if !intersects(self.bounds, visible) {
+            return;
+        }
+        let lines = &self.lines;
+        let projected = self.projected.get_or_insert_with(|| {
+            lines
+                .iter()
+                .flat_map(|line| coord.projected_runs(&coord.densified(line, false)))
+                .filter(|run| run.len() > 1)
+                .collect()
+        });
+        output.extend(coord.clipped_pixels(projected));
+        for child in self.children.iter_mut() {
+            child.collect(coord, visible, output);
+        }
+    }
+}
+
+/**
+A cache of projected `(lon, lat)` lines, e.g. the coastlines of a large vector basemap, for
+fast redraws.
+
+The lines are partitioned into a quadtree of geographic tiles. The lines of a tile are
+projected the first time it's visible and kept, so redrawing the map after panning it only
+maps the cached tiles in view to pixels. A change of projection is noticed and drops the
+cached projections.
+
+```rust
+use plotters::coord::geo::{LatLonCoord, Mercator, TileCache};
+
+let mut cache = TileCache::new(6);
+cache.insert(vec![(-5.0, 48.0), (2.0, 51.0), (8.0, 54.0)]);
+
+let coord = LatLonCoord::new(
+    Some((-10.0, 10.0)),
+    Some((40.0, 60.0)),
+    (0..400, 0..400),
+    Mercator::new().build(),
+);
+// Draw them on `chart.plotting_area().use_screen_coord()`
+let lines = cache.lines(&coord);
+assert_eq!(lines.len(), 1);
+```
+*/
+pub struct TileCache {
+    root: Tile,
+    max_depth: u32,
+    fingerprint: Option<Vec<Option<Range>>>,
+}
+
+impl TileCache {
+    /// Creates an empty cache, whose quadtree is at most `max_depth` levels deep below the
+    /// whole globe
+    pub fn new(max_depth: u32) -> Self {
+        Self {
+            root: Tile::new(((-180.0, 180.0), (-90.0, 90.0))),
+            max_depth,
+            fingerprint: None,
+        }
+    }
+
+    /// Adds the `(lon, lat)` polyline to the smallest tile containing it
+    pub fn insert(&mut self, line: Vec<Range>) {
+        if line.is_empty() {
+            return;
+        }
+        let bbox = line.iter().fold(
+            (
+                (f64::INFINITY, f64::NEG_INFINITY),
+                (f64::INFINITY, f64::NEG_INFINITY),
+            ),
+            |((w, e), (s, n)), p| ((w.min(p.0), e.max(p.0)), (s.min(p.1), n.max(p.1))),
+        );
+        self.root.insert(line, bbox, self.max_depth);
+    }
+
+    /// Drops every cached projection, e.g. after changing the input CRS of the map
+    pub fn invalidate(&mut self) {
+        self.root.invalidate();
+        self.fingerprint = None;
+    }
+
+    /// Returns the lines of the tiles within the [visible bounds](LatLonCoord::visible_bounds)
+    /// of `coord`, in backend pixels and clipped to its frame, projecting the tiles that
+    /// aren't cached yet. The lines are split where they leave the frame.
+    pub fn lines<T: ProjectionS>(&mut self, coord: &LatLonCoord<T>) -> Vec<Vec<BackendCoord>> {
+        let fingerprint: Vec<_> = PROBES.iter().map(|p| coord.proj.try_map(*p).ok()).collect();
+        if self.fingerprint.as_ref() != Some(&fingerprint) {
+            self.root.invalidate();
+            self.fingerprint = Some(fingerprint);
+        }
+
+        let mut output = vec![];
+        self.root
+            .collect(coord, coord.visible_bounds(), &mut output);
+        output
+    }
+}
+
+fn contains(outer: (Range, Range), inner: (Range, Range)) -> bool {
+    let within = |o: Range, i: Range| o.0 <= i.0 && i.1 <= o.1;
+    within(outer.0, inner.0) && within(outer.1, inner.1)
+}
+
+fn intersects(a: (Range, Range), b: (Range, Range)) -> bool {
+    let overlap = |a: Range, b: Range| a.0 <= b.1 && b.0 <= a.1;
+    overlap(a.0, b.0) && overlap(a.1, b.1)
+}
+
+#[cfg(all(test, feature = "proj"))]
+mod test {
+    use super::super::*;
+    use super::*;
+
+    fn europe(lon: Range) -> LatLonCoord<Mercator> {
+        LatLonCoord::new(
+            Some(lon),
+            Some((35.0, 60.0)),
+            (0..400, 0..400),
+            Mercator::new().build(),
+        )
+    }
+
+    /// The tile holding the line starting at `p`
+    fn tile_of(tile: &Tile, p: Range) -> &Tile {
+        if tile.lines.iter().any(|l| l[0] == p) {
+            return tile;
+        }
+        tile.children
+            .iter()
+            .find(|c| contains(c.bounds, ((p.0, p.0), (p.1, p.1))))
+            .map(|c| tile_of(c, p))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_tile_cache() {
+        let mut cache = TileCache::new(4);
+        cache.insert(vec![(-5.0, 48.0), (2.0, 51.0)]);
+        cache.insert(vec![(12.0, 45.0), (14.0, 41.0)]);
+        cache.insert(vec![(140.0, 35.0), (141.0, 40.0)]);
+        // Straddles the middle of the globe, kept in the root
+        cache.insert(vec![(-10.0, -10.0), (10.0, 10.0)]);
+
+        let lines = cache.lines(&europe((-10.0, 20.0)));
+        assert_eq!(lines.len(), 2);
+        // The far east tile is out of view and wasn't projected
+        assert!(tile_of(&cache.root, (-5.0, 48.0)).projected.is_some());
+        assert!(tile_of(&cache.root, (140.0, 35.0)).projected.is_none());
+        assert_eq!(
+            tile_of(&cache.root, (-10.0, -10.0)).bounds,
+            cache.root.bounds
+        );
+
+        // After panning, the cached tiles are mapped to the new pixels
+        let panned = europe((0.0, 30.0));
+        let lines = cache.lines(&panned);
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0]
+            .iter()
+            .all(|p| p.0 >= 0 && p.0 <= 400 && p.1 >= 0 && p.1 <= 400));
+        assert_eq!(
+            lines[0][0],
+            panned.translate(&(12.0, 45.0)),
+            "{:?}",
+            lines[0]
+        );
+    }
+
+    #[test]
+    fn test_tile_cache_projection_change() {
+        let mut cache = TileCache::new(4);
+        cache.insert(vec![(-5.0, 48.0), (2.0, 51.0)]);
+        let before = cache.lines(&europe((-10.0, 20.0)));
+
+        let moll = LatLonCoord::new(
+            Some((-10.0, 20.0)),
+            Some((35.0, 60.0)),
+            (0..400, 0..400),
+            Mollweide::new().build(),
+        );
+        let after = cache.lines(&moll);
+        assert_eq!(after[0][0], moll.translate(&(-5.0, 48.0)));
+        assert_ne!(before, after);
+    }
+}
@@ -0,0 +1,127 @@
+/// The finite values sorted ascending
+fn sorted(values: &[f64]) -> Vec<f64> {
+    let mut sorted: Vec<f64> = values.iter().copied().filter(|v| v.is_finite()).collect();
+    sorted.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+    sorted
+}
+
+/// Splits `values` into `classes` classes with the Jenks natural breaks, e.g. to bin the
+/// values of a choropleth map into colors: the classes minimize the sum of the squared
+/// deviations of the values from the mean of their class, so similar values end up together.
+///
+/// Returns the `classes + 1` boundaries, from the minimum to the maximum value, each class
+/// ending at its largest value. Non-finite values are ignored, and there are at most as many
+/// classes as values; no values or no classes give no boundaries.
+pub fn jenks_breaks(values: &[f64], classes: usize) -> Vec<f64> {
+    let x = sorted(values);
+    let (n, classes) = (x.len(), classes.min(x.len()));
+    if classes == 0 {
+        return vec![];
+    }
+
+    // The sums of the values and of their squares, to get the deviation of a run in O(1)
+    let mut sum = vec![0.0; n + 1];
+    let mut sum_sq = vec![0.0; n + 1];
+    for (i, v) in x.iter().enumerate() {
+        sum[i + 1] = sum[i] + v;
+        sum_sq[i + 1] = sum_sq[i] + v * v;
+    }
+    let deviation = |start: usize, end: usize| {
+        let (s, count) = (sum[end] - sum[start], (end - start) as f64);
+        sum_sq[end] - sum_sq[start] - s * s / count
+    };
+
+    // cost[k][j] is the best deviation of the first j values in k + 1 classes, and start[k][j]
+    // where its last class starts
+    let mut cost = vec![vec![f64::INFINITY; n + 1]; classes];
+    let mut start = vec![vec![0; n + 1]; classes];
+    for (j, c) in cost[0].iter_mut().enumerate().skip(1) {
+        *c = deviation(0, j);
+    }
+    for k in 1..classes {
+        for j in k + 1..=n {
+            for i in k..j {
+                let c = cost[k - 1][i] + deviation(i, j);
+                if c < cost[k][j] {
+                    cost[k][j] = c;
+                    start[k][j] = i;
+                }
+            }
+        }
+    }
+
+    let mut breaks = vec![x[n - 1]];
+    let mut end = n;
+    for k in (1..classes).rev() {
+        end = start[k][end];
+        breaks.push(x[end - 1]);
+    }
+    breaks.push(x[0]);
+    breaks.reverse();
+    breaks
+}
+
+/// Splits `values` into `classes` classes holding as many values each, with the boundaries
+/// interpolated between the sorted values. Returns the `classes + 1` boundaries like
+/// [`jenks_breaks`].
+pub fn quantile_breaks(values: &[f64], classes: usize) -> Vec<f64> {
+    let x = sorted(values);
+    if x.is_empty() || classes == 0 {
+        return vec![];
+    }
+    (0..=classes)
+        .map(|i| {
+            let rank = i as f64 / classes as f64 * (x.len() - 1) as f64;
+            let (below, t) = (rank.floor() as usize, rank.fract());
+            match x.get(below + 1) {
+                Some(above) => x[below] + (above - x[below]) * t,
+                None => x[below],
+            }
+        })
+        .collect()
+}
+
+/// Splits the range of `values` into `classes` classes of the same width. Returns the
+/// `classes + 1` boundaries like [`jenks_breaks`].
+pub fn equal_interval_breaks(values: &[f64], classes: usize) -> Vec<f64> {
+    let x = sorted(values);
+    let (min, max) = match (x.first(), x.last()) {
+        (Some(min), Some(max)) if classes > 0 => (*min, *max),
+        _ => return vec![],
+    };
+    (0..=classes)
+        .map(|i| min + (max - min) * i as f64 / classes as f64)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_jenks_breaks() {
+        let values = [1.0, 2.0, 4.0, 5.0, 7.0, 9.0, 10.0, 20.0];
+        assert_eq!(jenks_breaks(&values, 3), vec![1.0, 5.0, 10.0, 20.0]);
+
+        // The optimum found by trying every split
+        let values = [
+            12.0, 15.0, 1.0, 3.0, 27.0, 28.0, 30.0, 2.0, 14.0, 16.0, 45.0, 50.0, 48.0, 4.0,
+        ];
+        assert_eq!(jenks_breaks(&values, 4), vec![1.0, 4.0, 16.0, 30.0, 50.0]);
+
+        assert_eq!(jenks_breaks(&[3.0, f64::NAN, 1.0], 5), vec![1.0, 1.0, 3.0]);
+        assert!(jenks_breaks(&[], 3).is_empty());
+    }
+
+    #[test]
+    fn test_quantile_and_equal_interval_breaks() {
+        let values = [0.0, 1.0, 2.0, 3.0, 10.0];
+        assert_eq!(quantile_breaks(&values, 4), vec![0.0, 1.0, 2.0, 3.0, 10.0]);
+        assert_eq!(quantile_breaks(&values, 2), vec![0.0, 2.0, 10.0]);
+        assert_eq!(
+            equal_interval_breaks(&values, 4),
+            vec![0.0, 2.5, 5.0, 7.5, 10.0]
+        );
+        assert!(equal_interval_breaks(&values, 0).is_empty());
+    }
+}
@@ -0,0 +1,280 @@
+//! Clipping of geometry to the rectangular frame of a map or to a polygon.
+use super::{polygon_contains, Range};
+
+/// An axis-aligned rectangle as `((x_min, x_max), (y_min, y_max))`
+pub(super) type Rect = (Range, Range);
+
+/// Clips the segment from `a` to `b` to `rect` (Liang–Barsky), returning the visible part
+pub(super) fn clip_segment(a: Range, b: Range, rect: Rect) -> Option<(Range, Range)> {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let (mut t0, mut t1) = (0.0f64, 1.0f64);
+    let edges = [
+        (-dx, a.0 - rect.0 .0),
+        (dx, rect.0 .1 - a.0),
+        (-dy, a.1 - rect.1 .0),
+        (dy, rect.1 .1 - a.1),
+    ];
+    for (p, q) in edges {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else if p < 0.0 {
+            t0 = t0.max(q / p);
+        } else {
+            t1 = t1.min(q / p);
+        }
+    }
+    if t0 > t1 {
+        return None;
+    }
+    // Uncut ends are kept exact, so callers can tell whether the segment was cut
+    let at = |t: f64| {
+        if t == 0.0 {
+            a
+        } else if t == 1.0 {
+            b
+        } else {
+            (a.0 + dx * t, a.1 + dy * t)
+        }
+    };
+    Some((at(t0), at(t1)))
+}
+
+/// Clips the polyline to `rect`. A line leaving and re-entering the rectangle is split, so
+/// this returns every visible piece in order.
+pub(super) fn clip_polyline(points: &[Range], rect: Rect) -> Vec<Vec<Range>> {
+    let mut pieces: Vec<Vec<Range>> = vec![];
+    let mut open = false;
+    for w in points.windows(2) {
+        match clip_segment(w[0], w[1], rect) {
+            Some((a, b)) => {
+                if !open {
+                    pieces.push(vec![a]);
+                }
+                let piece = pieces.last_mut().unwrap();
+                piece.push(b);
+                // The piece continues only if the segment wasn't cut at its end
+                open = b == w[1];
+            }
+            None => open = false,
+        }
+    }
+    if points.len() == 1 && clip_segment(points[0], points[0], rect).is_some() {
+        pieces.push(vec![points[0]]);
+    }
+    pieces
+}
+
+/// Clips the closed `ring` to `rect` (Sutherland–Hodgman). Since the rectangle is convex,
+/// the result is a single ring, empty if the polygon lies outside of the rectangle.
+pub(super) fn clip_polygon(ring: &[Range], rect: Rect) -> Vec<Range> {
+    let ((x0, x1), (y0, y1)) = rect;
+    let edges: [(&dyn Fn(Range) -> f64, f64); 4] = [
+        (&|p: Range| p.0, x0),
+        (&|p: Range| -p.0, -x1),
+        (&|p: Range| p.1, y0),
+        (&|p: Range| -p.1, -y1),
+    ];
+
+    let mut output = ring.to_vec();
+    for (value, bound) in edges {
+        let input = std::mem::take(&mut output);
+        let inside = |p: Range| value(p) >= bound;
+        for (i, &cur) in input.iter().enumerate() {
+            let prev = input[(i + input.len() - 1) % input.len()];
+            let crossing = || {
+                let t = (bound - value(prev)) / (value(cur) - value(prev));
+                (prev.0 + (cur.0 - prev.0) * t, prev.1 + (cur.1 - prev.1) * t)
+            };
+            match (inside(prev), inside(cur)) {
+                (true, true) => output.push(cur),
+                (true, false) => output.push(crossing()),
+                (false, true) => {
+                    output.push(crossing());
+                    output.push(cur);
+                }
+                (false, false) => {}
+            }
+        }
+    }
+    output
+}
+
+/// Clips the polyline to the inside of the closed `ring`, which doesn't need to be convex
+/// (even-odd rule), returning every piece within it in order
+pub(super) fn clip_polyline_to_polygon(points: &[Range], ring: &[Range]) -> Vec<Vec<Range>> {
+    let mut pieces: Vec<Vec<Range>> = vec![];
+    let mut open = false;
+    for w in points.windows(2) {
+        let (a, b) = (w[0], w[1]);
+        let at = |t: f64| (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t);
+
+        // Cut the segment at its crossings with the ring and keep the parts inside
+        let mut cuts = vec![0.0, 1.0];
+        cuts.extend((0..ring.len()).filter_map(|i| {
+            segment_intersection((a, b), (ring[i], ring[(i + 1) % ring.len()])).map(|(t, _)| t)
+        }));
+        cuts.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        cuts.dedup();
+
+        for c in cuts.windows(2) {
+            if !polygon_contains(ring, at((c[0] + c[1]) / 2.0)) {
+                open = false;
+                continue;
+            }
+            if !open {
+                pieces.push(vec![at(c[0])]);
+                open = true;
+            }
+            pieces.last_mut().unwrap().push(at(c[1]));
+        }
+    }
+    pieces
+}
+
+/// Splits the closed `ring` at its self-intersections into simple loops. Filling every loop
+/// on its own covers the parts where the ring overlaps itself once, as the non-zero rule
+/// would, instead of leaving them out as the even-odd rule does.
+pub(super) fn split_self_intersections(ring: &[Range]) -> Vec<Vec<Range>> {
+    let mut loops = vec![];
+    let mut pending = vec![ring.to_vec()];
+    while let Some(ring) = pending.pop() {
+        match first_self_intersection(&ring) {
+            Some((i, j, x)) => {
+                let mut outer = ring[..=i].to_vec();
+                outer.push(x);
+                outer.extend_from_slice(&ring[j + 1..]);
+                let mut inner = vec![x];
+                inner.extend_from_slice(&ring[i + 1..=j]);
+                pending.push(outer);
+                pending.push(inner);
+            }
+            None if ring.len() >= 3 => loops.push(ring),
+            None => {}
+        }
+    }
+    loops
+}
+
+/// The first pair of edges `(i, j)`, `i < j`, of the closed ring crossing each other away
+/// from their ends, with the crossing point
+fn first_self_intersection(ring: &[Range]) -> Option<(usize, usize, Range)> {
+    let n = ring.len();
+    let edge = |i: usize| (ring[i], ring[(i + 1) % n]);
+    for i in 0..n {
+        for j in i + 2..n {
+            if i == 0 && j == n - 1 {
+                continue;
+            }
+            let (a, b) = edge(i);
+            match segment_intersection(edge(i), edge(j)) {
+                Some((t, u)) if t > 0.0 && t < 1.0 && u > 0.0 && u < 1.0 => {
+                    return Some((i, j, (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t)));
+                }
+                _ => {}
+            }
+        }
+    }
+    None
+}
+
+/// The parameters along the `(a, b)` segment and the `(c, d)` edge of their crossing, if any
+fn segment_intersection((a, b): (Range, Range), (c, d): (Range, Range)) -> Option<(f64, f64)> {
+    let r = (b.0 - a.0, b.1 - a.1);
+    let s = (d.0 - c.0, d.1 - c.1);
+    let denom = r.0 * s.1 - r.1 * s.0;
+    if denom == 0.0 {
+        return None;
+    }
+    let t = ((c.0 - a.0) * s.1 - (c.1 - a.1) * s.0) / denom;
+    let u = ((c.0 - a.0) * r.1 - (c.1 - a.1) * r.0) / denom;
+    if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+        Some((t, u))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const RECT: Rect = ((0.0, 10.0), (0.0, 10.0));
+
+    #[test]
+    fn test_clip_polyline() {
+        let inside = clip_polyline(&[(1.0, 1.0), (5.0, 5.0), (9.0, 1.0)], RECT);
+        assert_eq!(inside, vec![vec![(1.0, 1.0), (5.0, 5.0), (9.0, 1.0)]]);
+
+        // Leaves the frame through the top and comes back
+        let pieces = clip_polyline(&[(1.0, 5.0), (5.0, 15.0), (9.0, 5.0)], RECT);
+        assert_eq!(pieces.len(), 2);
+        assert_eq!(pieces[0], vec![(1.0, 5.0), (3.0, 10.0)]);
+        assert_eq!(pieces[1], vec![(7.0, 10.0), (9.0, 5.0)]);
+
+        assert!(clip_polyline(&[(-5.0, -5.0), (-1.0, 20.0)], RECT).is_empty());
+    }
+
+    #[test]
+    fn test_clip_polyline_to_polygon() {
+        // An L-shaped ring, the line crosses its notch
+        let ring = [
+            (0.0, 0.0),
+            (10.0, 0.0),
+            (10.0, 10.0),
+            (6.0, 10.0),
+            (6.0, 4.0),
+            (4.0, 4.0),
+            (4.0, 10.0),
+            (0.0, 10.0),
+        ];
+        let pieces = clip_polyline_to_polygon(&[(-5.0, 8.0), (15.0, 8.0)], &ring);
+        assert_eq!(
+            pieces,
+            vec![vec![(0.0, 8.0), (4.0, 8.0)], vec![(6.0, 8.0), (10.0, 8.0)]]
+        );
+
+        let pieces = clip_polyline_to_polygon(&[(2.0, 2.0), (8.0, 2.0), (8.0, 20.0)], &ring);
+        assert_eq!(pieces, vec![vec![(2.0, 2.0), (8.0, 2.0), (8.0, 10.0)]]);
+    }
+
+    #[test]
+    fn test_split_self_intersections() {
+        let square = [(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)];
+        assert_eq!(split_self_intersections(&square), vec![square.to_vec()]);
+
+        let bowtie = [(0.0, 0.0), (10.0, 10.0), (10.0, 0.0), (0.0, 10.0)];
+        let loops = split_self_intersections(&bowtie);
+        assert_eq!(loops.len(), 2);
+        assert!(loops
+            .iter()
+            .all(|l| l.len() == 3 && l.contains(&(5.0, 5.0))));
+
+        // The ring goes twice around, its loops overlap
+        let twice = [
+            (0.0, 0.0),
+            (10.0, 0.0),
+            (10.0, 10.0),
+            (1.0, 10.0),
+            (1.0, 1.0),
+            (9.0, 1.0),
+            (9.0, 9.0),
+            (0.0, 9.0),
+        ];
+        assert_eq!(split_self_intersections(&twice).len(), 2);
+    }
+
+    #[test]
+    fn test_clip_polygon() {
+        let square = [(5.0, 5.0), (15.0, 5.0), (15.0, 15.0), (5.0, 15.0)];
+        let clipped = clip_polygon(&square, RECT);
+        assert_eq!(clipped.len(), 4);
+        for p in [(5.0, 5.0), (10.0, 5.0), (10.0, 10.0), (5.0, 10.0)] {
+            assert!(clipped.contains(&p), "{:?} missing from {:?}", p, clipped);
+        }
+
+        let outside = [(20.0, 20.0), (30.0, 20.0), (30.0, 30.0)];
+        assert!(clip_polygon(&outside, RECT).is_empty());
+    }
+}
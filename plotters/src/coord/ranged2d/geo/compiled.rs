@@ -0,0 +1,133 @@
+use super::{normalize_lon, AngleUnit, LatLonCoord, Proj, ProjectionS, Range};
+use plotters_backend::BackendCoord;
+
+/// The linear mapping of one projected axis to pixels, rounded like the cartesian coordinate
+#[derive(Clone, Copy)]
+struct Axis {
+    start: f64,
+    span: f64,
+    pixel_start: i32,
+    pixels: i32,
+}
+
+impl Axis {
+    fn new((start, end): Range, (pixel_start, pixel_end): (i32, i32)) -> Self {
+        Self {
+            start,
+            span: end - start,
+            pixel_start,
+            pixels: pixel_end - pixel_start,
+        }
+    }
+
+    #[allow(clippy::float_cmp)]
+    fn apply(&self, v: f64) -> i32 {
+        if self.span == 0.0 {
+            return self.pixels / 2;
+        }
+        let length = (v - self.start) / self.span;
+        match self.pixels {
+            0 => self.pixel_start,
+            n if n > 0 => self.pixel_start + (f64::from(n) * length + 1e-3).floor() as i32,
+            n => self.pixel_start + (f64::from(n) * length - 1e-3).ceil() as i32,
+        }
+    }
+}
+
+/**
+The translation of a [`LatLonCoord`] flattened for hot loops, e.g. animations reprojecting
+many points every frame, created by [`LatLonCoord::compile`].
+
+The projected window and pixel range are taken once as plain numbers, along with the
+projection and the settings of the coordinate, so [`apply`](Self::apply) goes straight from
+`(lon, lat)` to pixels, giving the same pixels as
+[`translate`](crate::coord::CoordTranslate::translate). The transform borrows the coordinate
+and doesn't follow its later changes: compile it again after a zoom or a resize.
+*/
+pub struct CompiledTransform<'a, T: ProjectionS> {
+    proj: &'a T,
+    input_crs: Option<&'a Proj>,
+    radians: bool,
+    /// The central meridian the longitudes are wrapped around, if they are
+    central_lon: Option<f64>,
+    /// The projected width of the world and the middle of the window, if the map wraps
+    wrap: Option<(f64, f64)>,
+    x: Axis,
+    y: Axis,
+}
+
+impl<'a, T: ProjectionS> CompiledTransform<'a, T> {
+    /// Translates the `(lon, lat)` point to backend pixels. Like `translate`, it panics on
+    /// points that can't be projected.
+    pub fn apply(&self, lonlat: Range) -> BackendCoord {
+        let mut point = lonlat;
+        if self.radians {
+            point = (point.0.to_degrees(), point.1.to_degrees());
+        }
+        if let Some(central) = self.central_lon {
+            point.0 = central + normalize_lon(point.0 - central);
+        }
+        if let Some(transform) = self.input_crs {
+            point = transform.convert(point).unwrap();
+        }
+        let (mut x, y) = self.proj.map(point);
+        if let Some((width, middle)) = self.wrap {
+            x += width * ((middle - x) / width).round();
+        }
+        (self.x.apply(x), self.y.apply(y))
+    }
+}
+
+impl<T: ProjectionS> LatLonCoord<T> {
+    /// Flattens the translation of the map into a [`CompiledTransform`], for loops translating
+    /// many points with the map unchanged
+    pub fn compile(&self) -> CompiledTransform<'_, T> {
+        let ((x0, x1), _) = self.frame();
+        CompiledTransform {
+            proj: &self.proj,
+            input_crs: self.input_crs.as_deref(),
+            radians: self.angle_unit == AngleUnit::Radians,
+            central_lon: Some(self.proj.origin().0).filter(|_| self.wrap_longitude),
+            wrap: self.world_width().map(|width| (width, (x0 + x1) / 2.0)),
+            x: Axis::new(self.x, (self.pixel_range.0.start, self.pixel_range.0.end)),
+            y: Axis::new(self.y, (self.pixel_range.1.start, self.pixel_range.1.end)),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "proj"))]
+mod test {
+    use super::super::*;
+
+    #[test]
+    fn test_compiled_transform() {
+        let points: Vec<_> = (0..200)
+            .map(|i| {
+                let t = i as f64 / 200.0;
+                (-170.0 + 340.0 * t, -70.0 + 140.0 * (7.0 * t).fract())
+            })
+            .collect();
+
+        let world = LatLonCoord::new(None, None, (0..1000, 700..0), Mercator::new().build());
+        let compiled = world.compile();
+        for p in &points {
+            assert_eq!(compiled.apply(*p), world.translate(p));
+        }
+
+        // The settings of the coordinate are compiled too
+        let panned = LatLonCoord::new(
+            Some((100.0, 260.0)),
+            Some((-60.0, 60.0)),
+            (0..640, 0..480),
+            Mercator::new().build(),
+        )
+        .wrap_horizontal(true)
+        .wrap_longitude(true)
+        .with_angle_unit(AngleUnit::Radians);
+        let compiled = panned.compile();
+        for p in &points {
+            let p = (p.0.to_radians(), p.1.to_radians());
+            assert_eq!(compiled.apply(p), panned.translate(&p));
+        }
+    }
+}
@@ -0,0 +1,588 @@
+use super::clip::clip_polyline_to_polygon;
+use super::{densify, LatLonCoord, ProjectionS, Range};
+use crate::coord::{CoordTranslate, ReverseCoordTranslate};
+use plotters_backend::BackendCoord;
+
+/// How [`LatLonCoord::suggest_graticule_steps`] rounds the graticule spacing
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StepMode {
+    /// Decimal fractions of a degree, e.g. 0.5° or 0.1°
+    Decimal,
+    /// Minutes and seconds of arc, e.g. 30′ or 5′, as used on nautical and aviation charts
+    Sexagesimal,
+}
+
+/// The length, in pixels, of the segments smoothed graticule lines are flattened into
+const SMOOTH_STEP_PX: f64 = 4.0;
+
+/// The longest step, in degrees, of the parallels around a pole where the meridians meet,
+/// so they are drawn as arcs even when densification is off
+const POLAR_PARALLEL_STEP: f64 = 5.0;
+
+/// The number of points of a parallel checked to be at the same distance from the pole
+/// before it is drawn as a circle
+const CIRCLE_CHECK_POINTS: usize = 8;
+
+/// How far, in pixels, the points of a parallel drawn as a circle may be from it
+const CIRCLE_TOLERANCE_PX: f64 = 0.5;
+
+/// The minimum number of graticule lines the suggested steps give across an extent
+const MIN_GRATICULE_LINES: f64 = 5.0;
+
+/// Round spacings, in degrees, for graticules spanning at least a degree
+const DEGREE_STEPS: [f64; 8] = [90.0, 45.0, 30.0, 15.0, 10.0, 5.0, 2.0, 1.0];
+
+/// Round spacings, in minutes or seconds of arc, below a degree
+const SEXAGESIMAL_STEPS: [f64; 7] = [30.0, 20.0, 15.0, 10.0, 5.0, 2.0, 1.0];
+
+impl<T: ProjectionS> LatLonCoord<T> {
+    /// Suggests round `(lon_step, lat_step)` spacings, in degrees, giving a few graticule
+    /// lines across the extent of the map. Steps below a degree follow `mode`.
+    pub fn suggest_graticule_steps(&self, mode: StepMode) -> (f64, f64) {
+        let (lon, lat) = self.extent();
+        (
+            suggest_step((lon.1 - lon.0).abs(), mode),
+            suggest_step((lat.1 - lat.0).abs(), mode),
+        )
+    }
+
+    /// Returns the projected polyline, in backend pixels, of the `lon` meridian across the
+    /// latitude extent of the map. Points that can't be projected are left out.
+    /// On projections where the meridians meet at a pole within the extent, they all end on
+    /// the same pixel there.
+    pub fn meridian_line(&self, lon: f64) -> Vec<BackendCoord> {
+        let mut line = self.project_polyline(self.meridian_points(lon));
+        let (_, lat) = self.extent();
+        for (end, pole) in [(0, lat.0), (line.len().wrapping_sub(1), lat.1)] {
+            if let (Some(p), Some(pole)) = (line.get_mut(end), self.pole_pixel(pole)) {
+                *p = pole;
+            }
+        }
+        line
+    }
+
+    /// Returns the projected polyline, in backend pixels, of the `lat` parallel across the
+    /// longitude extent of the map. Points that can't be projected are left out.
+    ///
+    /// Around a pole where the meridians meet, the parallels are drawn as arcs whatever the
+    /// [resolution](Self::with_resolution), and the pole itself, a single point, is empty.
+    pub fn parallel_line(&self, lat: f64) -> Vec<BackendCoord> {
+        if self.pole_pixel(lat).is_some() {
+            return vec![];
+        }
+        self.project_polyline(self.parallel_points(lat))
+    }
+
+    /// Returns the `lat` parallel as a circle, its center and radius in backend pixels, where
+    /// it is one: on azimuthal projections centered on a pole, such as
+    /// [`PolarStereographic`](super::PolarStereographic), the parallels are circles around
+    /// the pole. Backends drawing circles natively then need a single element instead of
+    /// the many segments of [`parallel_line`](Self::parallel_line).
+    ///
+    /// Parallels that aren't circles on screen, don't make a whole turn within the longitude
+    /// extent or leave the pixel range give `None`, to be drawn as polylines.
+    pub fn parallel_circle(&self, lat: f64) -> Option<(BackendCoord, u32)> {
+        let (lon, _) = self.extent();
+        let pole = self.proj.origin();
+        if (lon.1 - lon.0).abs() < 360.0 - 1e-9 || (pole.1.abs() - 90.0).abs() > 1e-9 {
+            return None;
+        }
+        let center = self.pixel_f64(self.project_f64(pole)?);
+        let radii = (0..CIRCLE_CHECK_POINTS)
+            .map(|i| {
+                let lon = pole.0 + 360.0 * i as f64 / CIRCLE_CHECK_POINTS as f64;
+                let p = self.pixel_f64(self.project_f64((lon, lat))?);
+                Some((p.0 - center.0).hypot(p.1 - center.1))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let radius = radii[0];
+        if radius < 1.0
+            || radii
+                .iter()
+                .any(|r| (r - radius).abs() > CIRCLE_TOLERANCE_PX)
+        {
+            return None;
+        }
+
+        let inside = |v: f64, pixels: &std::ops::Range<i32>| {
+            let (a, b) = (f64::from(pixels.start), f64::from(pixels.end));
+            v - radius >= a.min(b) - CIRCLE_TOLERANCE_PX
+                && v + radius <= a.max(b) + CIRCLE_TOLERANCE_PX
+        };
+        if !inside(center.0, &self.pixel_range.0) || !inside(center.1, &self.pixel_range.1) {
+            return None;
+        }
+        Some((
+            (center.0.round() as i32, center.1.round() as i32),
+            radius.round() as u32,
+        ))
+    }
+
+    /// Returns the projected `(prime, antimeridian)` meridians within the longitude extent of
+    /// the map, to style them apart from the rest of the graticule. Both come as several
+    /// polylines in backend pixels: a whole turn of longitude has the antimeridian on both
+    /// edges, and the lines are split where they leave the frame or can't be projected.
+    pub fn special_meridians(&self) -> (Vec<Vec<BackendCoord>>, Vec<Vec<BackendCoord>>) {
+        let (lon, _) = self.extent();
+        let (mut prime, mut antimeridian) = (vec![], vec![]);
+        for v in graticule_values(lon, 180.0) {
+            let pieces = self.projected_pieces(&self.meridian_points(v));
+            if (v / 180.0).round() as i64 % 2 == 0 {
+                prime.extend(pieces);
+            } else {
+                antimeridian.extend(pieces);
+            }
+        }
+        (prime, antimeridian)
+    }
+
+    /// Returns the projected meridians at every multiple of `lon_step` followed by the
+    /// parallels at every multiple of `lat_step` within the extent of the map.
+    pub fn graticule_lines(&self, lon_step: f64, lat_step: f64) -> Vec<Vec<BackendCoord>> {
+        let (lon, lat) = self.extent();
+        let meridians = graticule_values(lon, lon_step).map(|v| self.meridian_line(v));
+        let parallels = graticule_values(lat, lat_step).map(|v| self.parallel_line(v));
+        meridians
+            .chain(parallels)
+            .filter(|line| line.len() > 1)
+            .collect()
+    }
+
+    /// Returns the projected graticule in two levels, to draw the major lines heavier than
+    /// the minor ones: the meridians and parallels at every multiple of `major` degrees, and
+    /// those at the other multiples of `minor`, leaving out the values already on a major
+    /// line. Each level lists its meridians then its parallels, like
+    /// [`graticule_lines`](Self::graticule_lines).
+    pub fn graticule_levels(
+        &self,
+        major: f64,
+        minor: f64,
+    ) -> (Vec<Vec<BackendCoord>>, Vec<Vec<BackendCoord>>) {
+        let (lon, lat) = self.extent();
+        let on_major = move |v: &f64| ((v / major).round() * major - v).abs() < 1e-9 * major;
+        let minor_values = |range| graticule_values(range, minor).filter(move |v| !on_major(v));
+
+        let meridians = minor_values(lon).map(|v| self.meridian_line(v));
+        let parallels = minor_values(lat).map(|v| self.parallel_line(v));
+        let minor_lines = meridians
+            .chain(parallels)
+            .filter(|line| line.len() > 1)
+            .collect();
+        (self.graticule_lines(major, major), minor_lines)
+    }
+
+    /// Like [`graticule_lines`](Self::graticule_lines), but only keeps the parts of the lines
+    /// within the `clip` polygon, a `(lon, lat)` ring such as a country boundary. The lines
+    /// are clipped before being projected, so the polygon edges are geographic.
+    pub fn graticule_lines_within(
+        &self,
+        lon_step: f64,
+        lat_step: f64,
+        clip: &[Range],
+    ) -> Vec<Vec<BackendCoord>> {
+        let (lon, lat) = self.extent();
+        let meridians = graticule_values(lon, lon_step).map(|v| self.meridian_points(v));
+        let parallels = graticule_values(lat, lat_step).map(|v| self.parallel_points(v));
+        meridians
+            .chain(parallels)
+            .flat_map(|line| clip_polyline_to_polygon(&line, clip))
+            .map(|piece| self.project_polyline(piece))
+            .filter(|line| line.len() > 1)
+            .collect()
+    }
+
+    /// Returns the nodes of the graticule with lines at every multiple of `lon_step` and
+    /// `lat_step` within the extent, each as its `(lon, lat)` and its pixel, e.g. to label or
+    /// snap to them. Nodes that can't be projected or aren't on screen are left out.
+    pub fn graticule_intersections(
+        &self,
+        lon_step: f64,
+        lat_step: f64,
+    ) -> Vec<(Range, BackendCoord)> {
+        let (lon, lat) = self.extent();
+        graticule_values(lon, lon_step)
+            .flat_map(|x| graticule_values(lat, lat_step).map(move |y| (x, y)))
+            .filter(|p| self.is_visible_degrees(*p))
+            .filter_map(|p| self.project_point(p).map(|px| (p, px)))
+            .collect()
+    }
+
+    /// Returns the `(lon, lat)` box of the graticule cell under the pixel `px`, for a grid
+    /// with lines at every multiple of `lon_step` and `lat_step`, or `None` if the pixel is
+    /// off the map. Useful to snap interactions to the grid.
+    pub fn pixel_to_graticule_cell(
+        &self,
+        px: BackendCoord,
+        lon_step: f64,
+        lat_step: f64,
+    ) -> Option<(Range, Range)> {
+        let (lon, lat) = self.reverse_translate(px)?;
+        let cell = |v: f64, step: f64| {
+            let start = (v / step).floor() * step;
+            (start, start + step)
+        };
+        Some((cell(lon, lon_step), cell(lat, lat_step)))
+    }
+
+    /// The densified `(lon, lat)` points of the `lon` meridian across the map
+    pub(super) fn meridian_points(&self, lon: f64) -> Vec<Range> {
+        let (_, lat) = self.extent();
+        densify((lon, lat.0), (lon, lat.1), self.densify_step())
+            .chain(Some((lon, lat.1)))
+            .collect()
+    }
+
+    /// The densified `(lon, lat)` points of the `lat` parallel across the map
+    pub(super) fn parallel_points(&self, lat: f64) -> Vec<Range> {
+        let (lon, _) = self.extent();
+        let step = match self.pole_pixel(90f64.copysign(lat)) {
+            Some(_) => self.densify_step().min(POLAR_PARALLEL_STEP),
+            None => self.densify_step(),
+        };
+        densify((lon.0, lat), (lon.1, lat), step)
+            .chain(Some((lon.1, lat)))
+            .collect()
+    }
+
+    /// The pixel of the pole at `lat`, if `lat` is a pole which the projection maps to a
+    /// single point, where the meridians meet
+    fn pole_pixel(&self, lat: f64) -> Option<BackendCoord> {
+        if (lat.abs() - 90.0).abs() > 1e-9 {
+            return None;
+        }
+        let pole = self.project_f64((self.proj.origin().0, lat))?;
+        let meets = [90.0, 180.0, 270.0].iter().all(|d| {
+            self.project_f64((self.proj.origin().0 + d, lat))
+                .map_or(false, |p| {
+                    self.cartesian.translate(&p) == self.cartesian.translate(&pole)
+                })
+        });
+        if meets {
+            Some(self.cartesian.translate(&pole))
+        } else {
+            None
+        }
+    }
+
+    fn project_polyline<I: IntoIterator<Item = Range>>(&self, points: I) -> Vec<BackendCoord> {
+        if self.smooth_graticule {
+            let projected: Vec<_> = points
+                .into_iter()
+                .filter_map(|p| self.project_f64(p))
+                .collect();
+            return self.flattened_spline(&projected);
+        }
+        points
+            .into_iter()
+            .filter_map(|p| self.project_point(p))
+            .collect()
+    }
+
+    /// Flattens the Catmull-Rom spline through the projected points into backend pixels, with
+    /// segments about `SMOOTH_STEP_PX` long. The spline goes through every point.
+    fn flattened_spline(&self, points: &[Range]) -> Vec<BackendCoord> {
+        let n = points.len();
+        let mut line: Vec<BackendCoord> = vec![];
+        for i in 0..n.saturating_sub(1) {
+            let (p0, p1, p2) = (points[i.saturating_sub(1)], points[i], points[i + 1]);
+            let p3 = points[(i + 2).min(n - 1)];
+            let (a, b) = (self.cartesian.translate(&p1), self.cartesian.translate(&p2));
+            let len = f64::from(b.0 - a.0).hypot(f64::from(b.1 - a.1));
+            let steps = (len / SMOOTH_STEP_PX).ceil().max(1.0) as usize;
+            line.extend((0..steps).map(|j| {
+                let p = catmull_rom(p0, p1, p2, p3, j as f64 / steps as f64);
+                self.cartesian.translate(&p)
+            }));
+        }
+        line.extend(points.last().map(|p| self.cartesian.translate(p)));
+        line.dedup();
+        line
+    }
+}
+
+/// The point at `t` in `[0, 1]` on the uniform Catmull-Rom segment from `p1` to `p2`
+fn catmull_rom(p0: Range, p1: Range, p2: Range, p3: Range, t: f64) -> Range {
+    let axis = |v0: f64, v1: f64, v2: f64, v3: f64| {
+        0.5 * (2.0 * v1
+            + (v2 - v0) * t
+            + (2.0 * v0 - 5.0 * v1 + 4.0 * v2 - v3) * t * t
+            + (3.0 * v1 - v0 - 3.0 * v2 + v3) * t * t * t)
+    };
+    (axis(p0.0, p1.0, p2.0, p3.0), axis(p0.1, p1.1, p2.1, p3.1))
+}
+
+/// The largest round step giving at least `MIN_GRATICULE_LINES` lines over `span` degrees
+fn suggest_step(span: f64, mode: StepMode) -> f64 {
+    let fits = |step: f64| span / step >= MIN_GRATICULE_LINES - 1e-9;
+    if let Some(step) = DEGREE_STEPS.iter().copied().find(|s| fits(*s)) {
+        return step;
+    }
+    match mode {
+        StepMode::Decimal => {
+            let mut magnitude = 0.1;
+            // Down to a nanodegree, far below anything displayable
+            while magnitude > 1e-9 {
+                for digit in [5.0, 2.0, 1.0].iter() {
+                    if fits(digit * magnitude) {
+                        return digit * magnitude;
+                    }
+                }
+                magnitude /= 10.0;
+            }
+            magnitude
+        }
+        StepMode::Sexagesimal => {
+            let minutes = SEXAGESIMAL_STEPS.iter().map(|m| m / 60.0);
+            let seconds = SEXAGESIMAL_STEPS.iter().map(|s| s / 3600.0);
+            minutes
+                .chain(seconds)
+                .find(|s| fits(*s))
+                .unwrap_or(1.0 / 3600.0)
+        }
+    }
+}
+
+/// The multiples of `step` within `range`
+pub(crate) fn graticule_values(range: Range, step: f64) -> impl Iterator<Item = f64> {
+    let start = (range.0.min(range.1) / step).ceil() as i64;
+    let end = (range.0.max(range.1) / step).floor() as i64;
+    (start..=end).map(move |i| i as f64 * step)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_graticule_values() {
+        let values: Vec<_> = graticule_values((-45.0, 100.0), 30.0).collect();
+        assert_eq!(values, vec![-30.0, 0.0, 30.0, 60.0, 90.0]);
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_suggest_graticule_steps() {
+        let new_coord = |lon, lat| {
+            LatLonCoord::new(
+                Some(lon),
+                Some(lat),
+                (0..100, 0..100),
+                Mercator::new().build(),
+            )
+        };
+
+        let world = new_coord((-180.0, 180.0), (-80.0, 80.0));
+        assert_eq!(
+            world.suggest_graticule_steps(StepMode::Decimal),
+            (45.0, 30.0)
+        );
+        assert_eq!(
+            world.suggest_graticule_steps(StepMode::Sexagesimal),
+            (45.0, 30.0)
+        );
+
+        let harbor = new_coord((-4.5, -4.0), (48.2, 48.5));
+        assert_eq!(
+            harbor.suggest_graticule_steps(StepMode::Decimal),
+            (0.1, 0.05)
+        );
+        let (lon_step, lat_step) = harbor.suggest_graticule_steps(StepMode::Sexagesimal);
+        assert!((lon_step - 5.0 / 60.0).abs() < 1e-12);
+        assert!((lat_step - 2.0 / 60.0).abs() < 1e-12);
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_graticule_lines() {
+        let coord = LatLonCoord::new(
+            Some((-180.0, 180.0)),
+            Some((-60.0, 60.0)),
+            (0..360, 0..240),
+            Mercator::new().build(),
+        );
+        // 13 meridians and 5 parallels
+        assert_eq!(coord.graticule_lines(30.0, 30.0).len(), 18);
+
+        let meridian = coord.meridian_line(0.0);
+        assert!(meridian.len() > 2);
+        assert!(meridian.iter().all(|p| p.0 == meridian[0].0));
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_graticule_levels() {
+        let coord = LatLonCoord::new(
+            Some((-180.0, 180.0)),
+            Some((-60.0, 60.0)),
+            (0..360, 0..240),
+            Mercator::new().build(),
+        );
+        let (major, minor) = coord.graticule_levels(30.0, 10.0);
+        assert_eq!(major, coord.graticule_lines(30.0, 30.0));
+        // 24 meridians and 8 parallels between the major ones
+        assert_eq!(minor.len(), 32);
+        assert!(minor.iter().all(|line| !major.contains(line)));
+        assert!(!minor.contains(&coord.meridian_line(0.0)));
+        assert!(minor.contains(&coord.meridian_line(10.0)));
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_polar_graticule() {
+        let arctic = LatLonCoord::new(
+            None,
+            Some((60.0, 90.0)),
+            (0..400, 0..400),
+            PolarStereographic::north().build(),
+        )
+        .with_resolution(None);
+
+        let pole = arctic.meridian_line(0.0).last().copied().unwrap();
+        assert!(graticule_values((-180.0, 180.0), 30.0)
+            .all(|lon| arctic.meridian_line(lon).last() == Some(&pole)));
+
+        // The parallels around the pole are arcs, and the pole is not a line
+        let parallel = arctic.parallel_line(80.0);
+        assert!(parallel.len() > 36);
+        assert!(parallel.iter().all(|p| *p != pole));
+        assert!(arctic.parallel_line(90.0).is_empty());
+        assert_eq!(arctic.graticule_lines(30.0, 10.0).len(), 13 + 3);
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_smooth_graticule() {
+        let new_coord = || {
+            LatLonCoord::new(
+                Some((-60.0, 60.0)),
+                Some((-60.0, 60.0)),
+                (0..400, 0..400),
+                Orthographic::new().center(0.0, 0.0).build(),
+            )
+            .with_resolution(Some(15.0))
+        };
+        let coarse = new_coord();
+        let smooth = new_coord().smooth_graticule(true);
+
+        let (facets, curve) = (coarse.parallel_line(30.0), smooth.parallel_line(30.0));
+        assert!(curve.len() > 2 * facets.len());
+        // The spline goes through the projected points, with the same ends
+        assert!(facets.iter().all(|p| curve.contains(p)));
+        assert_eq!(curve.first(), facets.first());
+        assert_eq!(curve.last(), facets.last());
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_special_meridians() {
+        let world = LatLonCoord::new(
+            Some((-180.0, 180.0)),
+            Some((-60.0, 60.0)),
+            (0..360, 0..240),
+            Mercator::new().build(),
+        );
+        let (prime, antimeridian) = world.special_meridians();
+        assert_eq!(prime.len(), 1);
+        assert!(prime[0].iter().all(|p| p.0 == 180));
+        assert_eq!(antimeridian.len(), 2);
+        assert!(antimeridian.iter().all(|l| l[0].0 == 0 || l[0].0 == 360));
+
+        let pacific = LatLonCoord::new(
+            Some((150.0, 210.0)),
+            Some((-60.0, 60.0)),
+            (0..360, 0..240),
+            LambertCylindrical::new().central_lon(180.0).build(),
+        );
+        let (prime, antimeridian) = pacific.special_meridians();
+        assert!(prime.is_empty());
+        assert_eq!(antimeridian.len(), 1);
+        assert!(antimeridian[0].iter().all(|p| p.0 == 180));
+    }
+
+    /// Draws lon/lat as is, only showing the eastern hemisphere
+    struct EastOnly;
+
+    impl ProjectionS for EastOnly {
+        fn bbox(&self, _: Option<Range>, _: Option<Range>) -> Result<(Range, Range), CoordError> {
+            Ok(((-180.0, 180.0), (-90.0, 90.0)))
+        }
+
+        fn map(&self, v: Range) -> Range {
+            v
+        }
+
+        fn is_visible(&self, p: Range) -> bool {
+            p.0 >= 0.0
+        }
+    }
+
+    #[test]
+    fn test_graticule_culls_back_points() {
+        let coord = LatLonCoord::new(None, None, (0..360, 0..180), EastOnly);
+        assert!(coord.meridian_line(-90.0).is_empty());
+        let equator = coord.parallel_line(0.0);
+        assert!(equator.len() > 2 && equator.iter().all(|p| p.0 >= 180));
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_graticule_lines_within() {
+        let coord = LatLonCoord::new(
+            Some((-20.0, 20.0)),
+            Some((-20.0, 20.0)),
+            (0..400, 0..400),
+            Mercator::new().build(),
+        );
+        let east = [(0.0, -30.0), (30.0, -30.0), (30.0, 30.0), (0.0, 30.0)];
+        let lines = coord.graticule_lines_within(10.0, 10.0, &east);
+        // The 0, 10 and 20 meridians, and the 5 parallels cut at the prime meridian
+        assert_eq!(lines.len(), 8);
+        assert!(lines.iter().flatten().all(|p| p.0 >= 199));
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_graticule_intersections() {
+        let world = LatLonCoord::new(
+            None,
+            None,
+            (0..360, 0..180),
+            PlateCarree::geographic().build(),
+        );
+        let nodes = world.graticule_intersections(30.0, 30.0);
+        // 13 meridians and 7 parallels, both poles included
+        assert_eq!(nodes.len(), 13 * 7);
+        assert!(nodes.contains(&((30.0, 60.0), (210, 150))));
+
+        let globe = LatLonCoord::new(
+            None,
+            None,
+            (0..200, 0..200),
+            Orthographic::new().center(0.0, 0.0).build(),
+        );
+        let nodes = globe.graticule_intersections(30.0, 30.0);
+        assert!(nodes.len() < 13 * 7);
+        assert!(nodes.iter().all(|(p, _)| p.0.abs() <= 90.0));
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_pixel_to_graticule_cell() {
+        let coord = LatLonCoord::new(
+            Some((-30.0, 30.0)),
+            Some((-30.0, 30.0)),
+            (0..600, 0..600),
+            Mercator::new().build(),
+        );
+        assert_eq!(
+            coord.pixel_to_graticule_cell((310, 310), 10.0, 10.0),
+            Some(((0.0, 10.0), (0.0, 10.0)))
+        );
+        assert_eq!(
+            coord.pixel_to_graticule_cell((290, 310), 10.0, 10.0),
+            Some(((-10.0, 0.0), (0.0, 10.0)))
+        );
+        assert_eq!(coord.pixel_to_graticule_cell((-5, 300), 10.0, 10.0), None);
+    }
+}
@@ -0,0 +1,96 @@
+use super::Range;
+
+/**
+A regular grid of values over a lon/lat box, e.g. a gridded temperature field.
+
+The values are row-major, `width` per row. The nodes are evenly spaced and span the whole
+box: the first node of the first row is at the `(lon.0, lat.0)` corner of the box and the
+last node of the last row at `(lon.1, lat.1)`.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid2D<T> {
+    width: usize,
+    height: usize,
+    values: Vec<T>,
+}
+
+impl<T> Grid2D<T> {
+    /// Creates a grid of `width` columns from its row-major `values`, or `None` if they don't
+    /// fill whole rows
+    pub fn new(width: usize, values: Vec<T>) -> Option<Self> {
+        if width == 0 || values.is_empty() || values.len() % width != 0 {
+            return None;
+        }
+        Some(Self {
+            width,
+            height: values.len() / width,
+            values,
+        })
+    }
+
+    /// The `(columns, rows)` of the grid
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    /// The value at column `col` of row `row`
+    pub fn get(&self, col: usize, row: usize) -> Option<&T> {
+        if col < self.width && row < self.height {
+            self.values.get(row * self.width + col)
+        } else {
+            None
+        }
+    }
+}
+
+impl Grid2D<f64> {
+    /// Bilinearly interpolates the grid spanning the `(lon, lat)` box `bounds` at `point`, or
+    /// `None` if the point is outside of the box
+    pub fn sample(&self, bounds: (Range, Range), point: Range) -> Option<f64> {
+        let position = |v: f64, (start, end): Range, nodes: usize| {
+            let t = (v - start) / (end - start);
+            if !(0.0..=1.0).contains(&t) {
+                return None;
+            }
+            let pos = t * (nodes - 1) as f64;
+            let i = (pos.floor() as usize).min(nodes.saturating_sub(2));
+            Some((i, pos - i as f64))
+        };
+        let (col, u) = position(point.0, bounds.0, self.width)?;
+        let (row, v) = position(point.1, bounds.1, self.height)?;
+        let at = |c: usize, r: usize| {
+            let c = c.min(self.width - 1);
+            let r = r.min(self.height - 1);
+            self.values[r * self.width + c]
+        };
+        let south = at(col, row) * (1.0 - u) + at(col + 1, row) * u;
+        let north = at(col, row + 1) * (1.0 - u) + at(col + 1, row + 1) * u;
+        Some(south * (1.0 - v) + north * v)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_grid_sample() {
+        assert!(Grid2D::new(3, vec![0.0; 4]).is_none());
+
+        // z = lon + 10 lat on the nodes of a 3 x 2 grid
+        let grid = Grid2D::new(3, vec![0.0, 1.0, 2.0, 10.0, 11.0, 12.0]).unwrap();
+        assert_eq!(grid.size(), (3, 2));
+        assert_eq!(grid.get(2, 1), Some(&12.0));
+        assert_eq!(grid.get(3, 0), None);
+
+        let bounds = ((0.0, 2.0), (0.0, 1.0));
+        let sample = |p| grid.sample(bounds, p).unwrap();
+        assert!((sample((0.5, 0.5)) - 5.5).abs() < 1e-12);
+        assert!((sample((1.25, 0.2)) - 3.25).abs() < 1e-12);
+        assert!((sample((2.0, 1.0)) - 12.0).abs() < 1e-12);
+        assert_eq!(grid.sample(bounds, (2.5, 0.5)), None);
+
+        let single_row = Grid2D::new(2, vec![1.0, 3.0]).unwrap();
+        assert_eq!(single_row.sample(bounds, (1.0, 0.3)), Some(2.0));
+    }
+}
@@ -0,0 +1,2216 @@
+use crate::coord::cartesian::MeshLine;
+use crate::coord::ranged1d::KeyPointHint;
+use crate::coord::{cartesian::Cartesian2d, types::RangedCoordf64, ReverseCoordTranslate};
+use crate::prelude::{
+    ChartBuilder, ChartContext, CoordTranslate, DrawingArea, DrawingAreaErrorKind, DrawingBackend,
+};
+#[cfg(not(feature = "proj"))]
+use no_proj::{Proj, ProjBuilder, ProjCreateError, ProjError};
+use plotters_backend::BackendCoord;
+#[cfg(feature = "proj")]
+use proj::{Proj, ProjBuilder, ProjCreateError, ProjError};
+use std::cmp::Ordering;
+use std::ops::Range as SRange;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use thiserror::Error;
+
+mod cache;
+mod classify;
+mod clip;
+mod compiled;
+mod geodesic;
+mod graticule;
+mod grid;
+#[cfg(not(feature = "proj"))]
+mod no_proj;
+mod path;
+mod projection;
+mod scale;
+mod shared_edges;
+mod simplify;
+#[cfg(feature = "chrono")]
+mod terminator;
+mod ticks;
+mod tiles;
+
+pub use cache::TileCache;
+pub use classify::{equal_interval_breaks, jenks_breaks, quantile_breaks};
+pub use compiled::CompiledTransform;
+pub use geodesic::{
+    bearing, distance, geodesic_bearing, geodesic_distance, geographic_area, spherical_bearing,
+    spherical_distance, Accuracy,
+};
+pub(crate) use graticule::graticule_values;
+pub use graticule::StepMode;
+pub use grid::Grid2D;
+pub use path::FillRule;
+pub use projection::*;
+pub use scale::nice_scale_length;
+pub use shared_edges::merge_shared_edges;
+pub use simplify::simplify;
+#[cfg(feature = "chrono")]
+pub use terminator::{subsolar_point, terminator};
+pub use ticks::FrameEdge;
+pub use tiles::{lonlat_to_tile, tile_to_lonlat};
+
+type Range = (f64, f64);
+
+/// The unit of the angles of the points given to [`LatLonCoord`], see
+/// [`LatLonCoord::with_angle_unit`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AngleUnit {
+    /// Longitudes and latitudes in degrees, the default
+    Degrees,
+    /// Longitudes and latitudes in radians
+    Radians,
+}
+
+/// A measure of the local distortion of a projection, see [`LatLonCoord::distortion_at`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DistortionMetric {
+    /// The area scale factor, see [`ProjectionS::area_scale_at`]
+    Area,
+    /// The maximum angular deformation in degrees, see
+    /// [`ProjectionS::angular_deformation_at`]
+    Angular,
+}
+
+#[derive(Error, Debug)]
+pub enum CoordError {
+    #[error("the projection hasn't been built")]
+    Uninital,
+    #[error("the projection has no inverse")]
+    NoInverse,
+    #[error("the pixel ({}, {}) isn't on the map", .0.0, .0.1)]
+    OffMap(BackendCoord),
+    #[error("the {projection} projection isn't available, it requires {requires}")]
+    Unavailable {
+        projection: &'static str,
+        requires: &'static str,
+        source: ProjCreateError,
+    },
+    #[error("the point ({}, {}) is outside of the domain of the projection", .0.0, .0.1)]
+    OutOfDomain(Range),
+    #[error("the point ({}, {}) is outside of the extent of the map", .0.0, .0.1)]
+    OutOfExtent(Range),
+    #[error("the latitudes were clamped to ({}, {}), the band the projection can reach", .0.0, .0.1)]
+    Clamped(Range),
+    #[error("the range ({}, {}) isn't ordered", .0.0, .0.1)]
+    UnorderedRange(Range),
+    #[error("failed to project the point")]
+    ProjError {
+        #[from]
+        source: ProjError,
+    },
+    #[error("failed to create the transformation")]
+    ProjCreateError {
+        #[from]
+        source: ProjCreateError,
+    },
+}
+
+pub struct LatLonCoord<T>
+where
+    T: ProjectionS,
+{
+    pub lon: Option<Range>,
+    pub lat: Option<Range>,
+    x: Range,
+    y: Range,
+    cartesian: Cartesian2d<RangedCoordf64, RangedCoordf64>,
+    pixel_range: (SRange<i32>, SRange<i32>),
+    proj: Rc<T>,
+    input_crs: Option<Rc<Proj>>,
+    input_epsg: Option<u32>,
+    grid_paths: Vec<PathBuf>,
+    resolution: Option<f64>,
+    tolerance: Option<f64>,
+    angle_unit: AngleUnit,
+    smooth_graticule: bool,
+    wrap_horizontal: bool,
+    wrap_longitude: bool,
+}
+
+// Not derived, as the projection is shared between the clones and needn't be `Clone`
+impl<T: ProjectionS> Clone for LatLonCoord<T> {
+    fn clone(&self) -> Self {
+        Self {
+            lon: self.lon,
+            lat: self.lat,
+            x: self.x,
+            y: self.y,
+            cartesian: self.cartesian.clone(),
+            pixel_range: self.pixel_range.clone(),
+            proj: self.proj.clone(),
+            input_crs: self.input_crs.clone(),
+            input_epsg: self.input_epsg,
+            grid_paths: self.grid_paths.clone(),
+            resolution: self.resolution,
+            tolerance: self.tolerance,
+            angle_unit: self.angle_unit,
+            smooth_graticule: self.smooth_graticule,
+            wrap_horizontal: self.wrap_horizontal,
+            wrap_longitude: self.wrap_longitude,
+        }
+    }
+}
+
+impl<T: ProjectionS> LatLonCoord<T> {
+    /// Creates the map of the `lon`/`lat` extent on the `actual` pixel range. A latitude
+    /// range the projection [clamps](CoordError::Clamped), e.g. one reaching the poles in
+    /// Mercator, is replaced with the clamped one.
+    pub fn new(
+        lon: Option<Range>,
+        lat: Option<Range>,
+        actual: (SRange<i32>, SRange<i32>),
+        proj: T,
+    ) -> Self {
+        Self::fitted(lon, lat, actual, proj, true).unwrap()
+    }
+
+    /// Creates the map like [`new`](Self::new), returning the error instead of panicking
+    /// when the projection can't bound the `lon`/`lat` extent. A latitude range the
+    /// projection would clamp is returned as the [`CoordError::Clamped`] warning, holding
+    /// the range `new` falls back to.
+    pub fn try_new(
+        lon: Option<Range>,
+        lat: Option<Range>,
+        actual: (SRange<i32>, SRange<i32>),
+        proj: T,
+    ) -> Result<Self, CoordError> {
+        Self::fitted(lon, lat, actual, proj, false)
+    }
+
+    /// Fits the projection to the `lon`/`lat` extent and creates the map of its projected
+    /// bounding box. A latitude range the projection clamps is replaced with the clamped one
+    /// if `clamp`, else returned as the warning.
+    fn fitted(
+        lon: Option<Range>,
+        lat: Option<Range>,
+        actual: (SRange<i32>, SRange<i32>),
+        mut proj: T,
+        clamp: bool,
+    ) -> Result<Self, CoordError> {
+        let (natural_lon, natural_lat) = proj.natural_extent();
+        proj.fit_extent(lon.unwrap_or(natural_lon), lat.unwrap_or(natural_lat));
+        let (lat, (x, y)) = match projected_bounds(&proj, lon, lat) {
+            Err(CoordError::Clamped(clamped)) if clamp => {
+                (Some(clamped), projected_bounds(&proj, lon, Some(clamped))?)
+            }
+            bbox => (lat, bbox?),
+        };
+        let cartesian = Cartesian2d::new(x.0..x.1, y.0..y.1, actual.clone());
+        Ok(Self::from_parts(lon, lat, x, y, cartesian, actual, proj))
+    }
+
+    /// Creates the map on an existing `cartesian` coordinate, e.g. one shared with an
+    /// unprojected layer, so both use the same pixel mapping. The ranges of `cartesian` are
+    /// trusted to be the projected bounding box of the `lon`/`lat` extent, which is only
+    /// checked in debug builds.
+    pub fn from_cartesian(
+        cartesian: Cartesian2d<RangedCoordf64, RangedCoordf64>,
+        lon: Option<Range>,
+        lat: Option<Range>,
+        mut proj: T,
+    ) -> Self {
+        let (natural_lon, natural_lat) = proj.natural_extent();
+        proj.fit_extent(lon.unwrap_or(natural_lon), lat.unwrap_or(natural_lat));
+        let (x, y) = (cartesian.get_x_range(), cartesian.get_y_range());
+        let (x, y) = ((x.start, x.end), (y.start, y.end));
+        debug_assert!(
+            proj.bbox(lon, lat).map_or(false, |bbox| {
+                let close = |a: Range, b: Range| {
+                    let eps = 1e-9 * (a.1 - a.0).abs().max(1.0);
+                    (a.0 - b.0).abs() <= eps && (a.1 - b.1).abs() <= eps
+                };
+                close(bbox.0, x) && close(bbox.1, y)
+            }),
+            "the cartesian ranges don't match the projected extent"
+        );
+
+        // The ends of the ranges land on the ends of the pixel range, in its orientation
+        let start = cartesian.translate(&(x.0, y.0));
+        let end = cartesian.translate(&(x.1, y.1));
+        let pixel_range = (start.0..end.0, start.1..end.1);
+        Self::from_parts(lon, lat, x, y, cartesian, pixel_range, proj)
+    }
+
+    /// Creates the map of the window `x`/`y` given in projected units, e.g. the bounding box
+    /// of a web map viewport in meters, used as is instead of projecting a lon/lat extent.
+    /// The `lon` and `lat` fields are left unset: [`visible_bounds`](Self::visible_bounds)
+    /// maps the window back to lon/lat when needed. Both ranges must be ordered, start below
+    /// end.
+    pub fn from_projected_extent(
+        x: Range,
+        y: Range,
+        actual: (SRange<i32>, SRange<i32>),
+        proj: T,
+    ) -> Result<Self, CoordError> {
+        for range in [x, y].iter() {
+            // NaN ends aren't ordered either
+            if range.0.partial_cmp(&range.1) != Some(Ordering::Less) {
+                return Err(CoordError::UnorderedRange(*range));
+            }
+        }
+        let cartesian = Cartesian2d::new(x.0..x.1, y.0..y.1, actual.clone());
+        Ok(Self::from_parts(None, None, x, y, cartesian, actual, proj))
+    }
+
+    /// The map of the `x`/`y` window drawn on `cartesian`, with the default settings
+    fn from_parts(
+        lon: Option<Range>,
+        lat: Option<Range>,
+        x: Range,
+        y: Range,
+        cartesian: Cartesian2d<RangedCoordf64, RangedCoordf64>,
+        pixel_range: (SRange<i32>, SRange<i32>),
+        proj: T,
+    ) -> Self {
+        Self {
+            lon,
+            lat,
+            x,
+            y,
+            cartesian,
+            pixel_range,
+            proj: Rc::new(proj),
+            input_crs: None,
+            input_epsg: None,
+            grid_paths: vec![],
+            resolution: Some(DENSIFY_STEP),
+            tolerance: None,
+            angle_unit: AngleUnit::Degrees,
+            smooth_graticule: false,
+            wrap_horizontal: false,
+            wrap_longitude: false,
+        }
+    }
+
+    /// Declares that the incoming lon/lat data is on the geographic CRS `EPSG:<epsg>`
+    /// (e.g. `4267` for NAD27) rather than WGS84. Every translated point is then shifted
+    /// to WGS84 before the projection is applied.
+    ///
+    /// The extent keeps being interpreted as WGS84, since datum shifts are far below the
+    /// precision a bounding box needs.
+    ///
+    /// The datum shift uses the grid files found on the [grid paths](Self::add_grid_path),
+    /// and falls back to a less accurate approximation without them. PROJ can also download
+    /// the grids from its CDN, when its network access is enabled with the `PROJ_NETWORK=ON`
+    /// environment variable.
+    ///
+    /// Without the `proj` feature, this fails with [`CoordError::ProjCreateError`].
+    pub fn with_input_crs(mut self, epsg: u32) -> Result<Self, CoordError> {
+        self.input_epsg = Some(epsg);
+        self.update_input_crs()?;
+        Ok(self)
+    }
+
+    /// Adds a directory PROJ searches for the grid files of the datum shift of
+    /// [`with_input_crs`](Self::with_input_crs), after its default locations. The shift is
+    /// recreated if the input CRS is already set, failing like `with_input_crs`.
+    pub fn add_grid_path(mut self, path: PathBuf) -> Result<Self, CoordError> {
+        self.grid_paths.push(path);
+        self.update_input_crs()?;
+        Ok(self)
+    }
+
+    /// Creates the datum shift of the input CRS, if any, with the grid paths
+    fn update_input_crs(&mut self) -> Result<(), CoordError> {
+        let epsg = match self.input_epsg {
+            Some(epsg) => epsg,
+            None => return Ok(()),
+        };
+        let mut builder = ProjBuilder::new();
+        for path in &self.grid_paths {
+            builder.set_search_paths(path)?;
+        }
+        let transform = builder.proj_known_crs(&format!("EPSG:{}", epsg), "EPSG:4326", None)?;
+        self.input_crs = Some(Rc::new(transform));
+        Ok(())
+    }
+
+    /// Sets the maximum length, in degrees, of the edges of geographic lines and shapes
+    /// before they get projected, so they follow the curvature of the projection. Defaults
+    /// to one degree; `None` projects the given points only.
+    pub fn with_resolution(mut self, resolution: Option<f64>) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// Makes geographic lines and shapes subdivide their edges adaptively, until they are
+    /// within `tolerance_px` pixels of the projected curve, instead of following the fixed
+    /// [`resolution`](Self::with_resolution). `None` goes back to the fixed resolution.
+    pub fn with_tolerance(mut self, tolerance_px: Option<f64>) -> Self {
+        self.tolerance = tolerance_px;
+        self
+    }
+
+    /// Sets the unit of the `(lon, lat)` points drawn on the map, and of the points mapped
+    /// back from pixels, so data carried in radians doesn't need converting. Defaults to
+    /// degrees. The extent and the graticule helpers keep taking degrees.
+    pub fn with_angle_unit(mut self, unit: AngleUnit) -> Self {
+        self.angle_unit = unit;
+        self
+    }
+
+    /// Makes the graticule lines follow a Catmull-Rom spline through their projected points
+    /// instead of straight segments between them, hiding the facets of coarse resolutions.
+    /// The splines are flattened into polylines a few pixels per segment, as backends don't
+    /// draw curves.
+    pub fn smooth_graticule(mut self, smooth: bool) -> Self {
+        self.smooth_graticule = smooth;
+        self
+    }
+
+    /// Makes the map wrap around the globe horizontally, for scrolling world maps that pan
+    /// past the antimeridian: points are drawn within half a world width of the middle of
+    /// the window whatever their longitude, and lines are repeated every world width across
+    /// the window, so features near the seam show on both sides of it. Meant for cylindrical
+    /// projections, where a full turn of longitude is a fixed width.
+    pub fn wrap_horizontal(mut self, wrap: bool) -> Self {
+        self.wrap_horizontal = wrap;
+        self
+    }
+
+    /// Wraps the longitude of every translated point into the turn centered on the central
+    /// meridian of the projection (see [`normalize_lon`]), so data stored in `[0, 360)`
+    /// lands where it belongs on a `±180` map: 350° is drawn at -10°. Defaults to off.
+    pub fn wrap_longitude(mut self, wrap: bool) -> Self {
+        self.wrap_longitude = wrap;
+        self
+    }
+
+    /// Checks whether the `(lon, lat)` point is currently drawn on screen: it must be
+    /// projectable, face the viewer, fall within the projected window and, for projections
+    /// with a bounded domain, lie within their [`outline`](ProjectionS::outline). Points on
+    /// the far side of an orthographic globe are not visible. The point is taken like
+    /// [`translate`](CoordTranslate::translate) takes it, in the
+    /// [angle unit](Self::with_angle_unit) and wrapped like the drawn points.
+    pub fn is_visible(&self, point: Range) -> bool {
+        self.is_visible_degrees(self.in_degrees(point))
+    }
+
+    /// Like [`is_visible`](Self::is_visible), for a point already in degrees, e.g. a node
+    /// of the graticule
+    pub(crate) fn is_visible_degrees(&self, point: Range) -> bool {
+        self.project_f64(point).map_or(false, |projected| {
+            self.within_frame(self.wrapped(projected))
+        })
+    }
+
+    /// Returns the `(lon, lat)` bounds of the part of the globe currently on screen, found by
+    /// mapping a grid over the frame back through the inverse projection. All longitudes are
+    /// visible when a pole is. Projections without an inverse give the extent of the map.
+    pub fn visible_bounds(&self) -> (Range, Range) {
+        const SAMPLES: usize = 16;
+        let ((x0, x1), (y0, y1)) = self.frame();
+        let mut lon = (f64::INFINITY, f64::NEG_INFINITY);
+        let mut lat = (f64::INFINITY, f64::NEG_INFINITY);
+        for i in 0..=SAMPLES {
+            for j in 0..=SAMPLES {
+                let p = (
+                    x0 + (x1 - x0) * i as f64 / SAMPLES as f64,
+                    y0 + (y1 - y0) * j as f64 / SAMPLES as f64,
+                );
+                if !self.within_frame(p) {
+                    continue;
+                }
+                if let Ok((u, v)) = self.proj.inverse(p) {
+                    if u.is_finite() && v.is_finite() {
+                        lon = (lon.0.min(u), lon.1.max(u));
+                        lat = (lat.0.min(v), lat.1.max(v));
+                    }
+                }
+            }
+        }
+        if lon.0 > lon.1 {
+            return self.extent();
+        }
+        for pole in [-90.0, 90.0].iter().copied() {
+            if self.is_visible_degrees((0.0, pole)) {
+                lon = (-180.0, 180.0);
+                lat = (lat.0.min(pole), lat.1.max(pole));
+            }
+        }
+        (lon, lat)
+    }
+
+    /// Returns the projected SW, SE, NE and NW corners of the extent, in draw order. Unlike
+    /// the bounding box, they keep the shape of the frame where it isn't a rectangle, e.g.
+    /// the trapezoid of a conic map, for frame strokes or hit-testing.
+    pub fn projected_corners(&self) -> [Range; 4] {
+        let ((w, e), (s, n)) = self.extent();
+        [(w, s), (e, s), (e, n), (w, n)].map(|corner| self.proj.map(corner))
+    }
+
+    /// Returns the `(lon, lat)` points sampled to compute the projected bounding box of the
+    /// extent, to plot them as dots when the extent of a map looks wrong. Where they land is
+    /// given by [`translate`](CoordTranslate::translate), and the samples that can't be
+    /// projected are skipped by the bbox. Projections overriding
+    /// [`ProjectionS::bbox`] may not sample these points.
+    pub fn debug_sample_points(&self) -> Vec<Range> {
+        let (lon, lat) = self.extent();
+        bbox_samples(&*self.proj, lon, lat)
+    }
+
+    /// Checks the `(lon, lat)` points before plotting them, to fail with a clear message
+    /// rather than draw a blank map, e.g. when longitudes and latitudes are swapped. The
+    /// first point outside of the latitudes the projection can show or that it can't project
+    /// gives [`CoordError::OutOfDomain`], and the first point outside of the extent set for
+    /// the map gives [`CoordError::OutOfExtent`]. Longitudes are compared modulo a full turn.
+    pub fn validate_data<I: IntoIterator<Item = Range>>(
+        &self,
+        points: I,
+    ) -> Result<(), CoordError> {
+        let valid_lat = self.valid_lat();
+        let within = |v: f64, (a, b): Range| v >= a.min(b) && v <= a.max(b);
+        for point in points {
+            let (lon, lat) = self.in_degrees(point);
+            if !lon.is_finite() || !within(lat, valid_lat) || self.proj.try_map((lon, lat)).is_err()
+            {
+                return Err(CoordError::OutOfDomain(point));
+            }
+            let in_lon = self.lon.map_or(true, |(w, e)| {
+                let (w, e) = (w.min(e), w.max(e));
+                e - w >= 360.0 || within(w + (lon - w).rem_euclid(360.0), (w, e))
+            });
+            let in_lat = self.lat.map_or(true, |range| within(lat, range));
+            if !in_lon || !in_lat {
+                return Err(CoordError::OutOfExtent(point));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks whether the extent of the map covers the whole globe: a full turn of longitude
+    /// and every latitude the projection can show (see [`ProjectionS::natural_extent`]).
+    /// Missing ranges cover the globe.
+    pub fn covers_globe(&self) -> bool {
+        let (_, valid_lat) = self.proj.natural_extent();
+        let lon = self
+            .lon
+            .map_or(true, |(a, b)| (b - a).abs() >= 360.0 - 1e-9);
+        let lat = self.lat.map_or(true, |(a, b)| {
+            a.min(b) <= valid_lat.0.min(valid_lat.1) && a.max(b) >= valid_lat.0.max(valid_lat.1)
+        });
+        lon && lat
+    }
+
+    /// Returns the local distortion of the projection at the `(lon, lat)` point, in degrees
+    /// whatever the [angle unit](Self::with_angle_unit), measured by `metric`
+    pub fn distortion_at(&self, point: Range, metric: DistortionMetric) -> f64 {
+        match metric {
+            DistortionMetric::Area => self.proj.area_scale_at(point),
+            DistortionMetric::Angular => self.proj.angular_deformation_at(point),
+        }
+    }
+
+    /// Returns the area, in square meters, of the `(lon, lat)` polygon `ring` on the projected
+    /// plane, from the shoelace formula over its projected vertices, whatever the winding of
+    /// the ring. Compared with its [`geographic_area`], it tells how much the projection
+    /// inflates or shrinks the polygon. Vertices that can't be projected are left out, and
+    /// the edges are straight once projected, so long edges should be densified first.
+    pub fn projected_area(&self, ring: &[Range]) -> f64 {
+        let points: Vec<_> = ring
+            .iter()
+            .filter_map(|p| self.proj.try_map(self.in_degrees(*p)).ok())
+            .collect();
+        let twice: f64 = (0..points.len())
+            .map(|i| {
+                let (a, b) = (points[i], points[(i + 1) % points.len()]);
+                a.0 * b.1 - b.0 * a.1
+            })
+            .sum();
+        twice.abs() / 2.0 * self.proj.meters_per_unit().powi(2)
+    }
+
+    /// Returns where the natural origin of the projection (see [`ProjectionS::origin`])
+    /// lands in backend pixels, e.g. to place a compass rose, or `None` if it's off the map
+    pub fn origin_pixel(&self) -> Option<BackendCoord> {
+        let origin = self.proj.origin();
+        if !self.proj.is_visible(origin) {
+            return None;
+        }
+        match self.proj.try_map(origin) {
+            Ok(v) if self.within_frame(v) => Some(self.cartesian.translate(&v)),
+            _ => None,
+        }
+    }
+
+    /// Like [`translate`](CoordTranslate::translate), but keeps the fractional part of the
+    /// pixel coordinates, for drawing with sub-pixel precision, e.g. on high-DPI or vector
+    /// outputs.
+    ///
+    /// This is only an accessor: the [`DrawingBackend`] API takes whole pixels, so series and
+    /// elements are still drawn through `translate`. Use it to place geometry written to the
+    /// output directly, e.g. the path data of an SVG document.
+    pub fn translate_f64(&self, from: &Range) -> (f64, f64) {
+        let projected = self
+            .proj
+            .map(self.to_wgs84(self.in_degrees(*from)).unwrap());
+        self.pixel_f64(self.wrapped(projected))
+    }
+
+    /// Maps the projected point to backend pixels, keeping their fractional part
+    fn pixel_f64(&self, (x, y): Range) -> (f64, f64) {
+        let axis = |v: f64, (start, end): Range, pixels: &SRange<i32>| {
+            let (p0, p1) = (f64::from(pixels.start), f64::from(pixels.end));
+            if start == end {
+                return (p0 + p1) / 2.0;
+            }
+            p0 + (v - start) / (end - start) * (p1 - p0)
+        };
+        (
+            axis(x, self.x, &self.pixel_range.0),
+            axis(y, self.y, &self.pixel_range.1),
+        )
+    }
+
+    /// Translates many `(lon, lat)` points to backend pixels at once, projecting them all
+    /// with a single [`map_many`](ProjectionS::map_many) call. Points that can't be projected
+    /// or face away from the viewer give `None`.
+    pub fn translate_many(&self, points: &[Range]) -> Vec<Option<BackendCoord>> {
+        let points: Vec<Option<Range>> = points
+            .iter()
+            .map(|p| {
+                let p = self.to_wgs84(self.in_degrees(*p)).ok()?;
+                Some(p).filter(|p| self.proj.is_visible(*p))
+            })
+            .collect();
+        let valid: Vec<Range> = points.iter().flatten().copied().collect();
+        let mut projected = self.proj.map_many(&valid).into_iter();
+        points
+            .iter()
+            .map(|p| match p {
+                Some(_) => projected.next().flatten(),
+                None => None,
+            })
+            .map(|v| {
+                v.filter(|v| v.0.is_finite() && v.1.is_finite())
+                    .map(|v| self.cartesian.translate(&v))
+            })
+            .collect()
+    }
+
+    /// Translates the `(lon, lat)` points of `points` to backend pixels lazily, one at a time,
+    /// like [`translate_many`](Self::translate_many) but without collecting them, to stream
+    /// huge datasets with bounded memory. Points that can't be projected or face away from
+    /// the viewer give `None`.
+    pub fn project_iter<'a, I: Iterator<Item = Range> + 'a>(
+        &'a self,
+        points: I,
+    ) -> impl Iterator<Item = Option<BackendCoord>> + 'a {
+        points.map(move |p| self.project_point(self.in_degrees(p)))
+    }
+
+    /// Whether the projection of the map goes through PROJ, or through closed-form equations
+    /// in Rust, e.g. because PROJ is unavailable (see [`ProjectionS::uses_proj`])
+    pub fn uses_proj(&self) -> bool {
+        self.proj.uses_proj()
+    }
+
+    /// Returns the denominator `N` of the representative fraction `1:N` of the map printed at
+    /// `dpi` dots per inch: how many meters on the ground a meter on paper stands for. The
+    /// ground length of a pixel is taken in the projected plane, in the
+    /// [units](ProjectionS::meters_per_unit) of the projection, so it is true where the
+    /// projection keeps its scale, e.g. along the equator of a Mercator map.
+    pub fn scale_denominator(&self, dpi: f64) -> f64 {
+        let ((x0, x1), _) = self.frame();
+        let pixels = self.cartesian.get_x_axis_pixel_range();
+        let pixels = f64::from((pixels.end - pixels.start).max(1));
+        let meters_per_pixel = (x1 - x0) / pixels * self.proj.meters_per_unit();
+        meters_per_pixel / (METERS_PER_INCH / dpi)
+    }
+
+    /// Updates the pixel range the map is drawn on, e.g. after a window resize. The
+    /// geographic extent and its projected bounding box are kept as they are.
+    pub fn resize(&mut self, actual: (SRange<i32>, SRange<i32>)) {
+        self.pixel_range = actual;
+        self.update_cartesian();
+    }
+
+    /// Zooms the map by `factor` about the pixel `focus_px`, e.g. under the cursor on a
+    /// scroll-wheel event: the lon/lat extent is scaled by `1 / factor` about the point under
+    /// the pixel, so it stays in place, then the projected window is rebuilt. A `factor` above
+    /// `1.0` zooms in. The latitudes are clamped to the projection's natural extent.
+    ///
+    /// Returns the zoomed map, sharing the projection with this one, which is left as it is,
+    /// e.g. to zoom the map a chart is drawn on through
+    /// [`as_coord_spec`](crate::chart::ChartContext::as_coord_spec). Fails if the pixel is off
+    /// the map.
+    pub fn zoom_to(&self, focus_px: BackendCoord, factor: f64) -> Result<Self, CoordError> {
+        let projected = self
+            .cartesian
+            .reverse_translate(focus_px)
+            .filter(|p| self.within_frame(*p))
+            .ok_or(CoordError::OffMap(focus_px))?;
+        let focus = self.proj.inverse(projected)?;
+
+        let ((w, e), (s, n)) = self.extent();
+        let scale = |a: f64, c: f64| c + (a - c) / factor;
+        let (lat_min, lat_max) = self.valid_lat();
+        let lon = (scale(w, focus.0), scale(e, focus.0));
+        let lat = (
+            scale(s, focus.1).max(lat_min).min(lat_max),
+            scale(n, focus.1).max(lat_min).min(lat_max),
+        );
+        self.with_extent(lon, lat)
+    }
+
+    /// Pans the map as if dragged by `(dx, dy)` pixels: the lon/lat under the center of the
+    /// map and under the center moved back by the drag give the shift of the extent. The
+    /// longitudes wrap, keeping the center of the extent within ±180°, so the world can be
+    /// dragged round, also across the antimeridian; the latitudes stop at the projection's
+    /// natural extent, keeping their span.
+    ///
+    /// Returns the panned map, sharing the projection with this one, which is left as it is,
+    /// like [`zoom_to`](Self::zoom_to). Fails if the center of the map can't be inverse
+    /// projected.
+    pub fn pan_pixels(&self, dx: i32, dy: i32) -> Result<Self, CoordError> {
+        let (px, py) = &self.pixel_range;
+        let unit = (
+            (self.x.1 - self.x.0) / f64::from(px.end - px.start),
+            (self.y.1 - self.y.0) / f64::from(py.end - py.start),
+        );
+        let center = ((self.x.0 + self.x.1) / 2.0, (self.y.0 + self.y.1) / 2.0);
+        let moved = (
+            center.0 - f64::from(dx) * unit.0,
+            center.1 - f64::from(dy) * unit.1,
+        );
+        let (from, to) = (self.proj.inverse(center)?, self.proj.inverse(moved)?);
+
+        let ((w, e), (s, n)) = self.extent();
+        // The shortest way round, as the inverse projection may wrap either point
+        let d_lon = normalize_lon(to.0 - from.0);
+        let (half, center) = ((e - w) / 2.0, normalize_lon((w + e) / 2.0 + d_lon));
+        let lon = (center - half, center + half);
+
+        let (lat_min, lat_max) = self.valid_lat();
+        let (south, north) = (s.min(n), s.max(n));
+        let d_lat = (to.1 - from.1).max(lat_min - south).min(lat_max - north);
+        let lat = if north - south >= lat_max - lat_min {
+            (lat_min, lat_max)
+        } else {
+            (s + d_lat, n + d_lat)
+        };
+        self.with_extent(lon, lat)
+    }
+
+    /// A copy of the map showing the lon/lat extent, with the projected window rebuilt
+    fn with_extent(&self, lon: Range, lat: Range) -> Result<Self, CoordError> {
+        let mut map = self.clone();
+        map.set_extent(lon, lat)?;
+        Ok(map)
+    }
+
+    /// Replaces the lon/lat extent and rebuilds the projected window, or leaves the map
+    /// unchanged if the extent can't be projected
+    fn set_extent(&mut self, lon: Range, lat: Range) -> Result<(), CoordError> {
+        let (x, y) = self.proj.bbox(Some(lon), Some(lat))?;
+        self.lon = Some(lon);
+        self.lat = Some(lat);
+        self.x = x;
+        self.y = y;
+        self.update_cartesian();
+        Ok(())
+    }
+
+    /// The latitudes the projection can show, south to north
+    fn valid_lat(&self) -> Range {
+        let (_, lat) = self.proj.natural_extent();
+        (lat.0.min(lat.1), lat.0.max(lat.1))
+    }
+
+    /// Expands the projected window so the extent is drawn `px` pixels away from every edge
+    /// of the pixel range. Unlike padding the extent in degrees, the margin is the same on
+    /// screen at any latitude. Margins leaving no room for the map are ignored.
+    pub fn with_projected_margin(mut self, px: i32) -> Self {
+        let pad = |(a, b): Range, pixels: &SRange<i32>| {
+            let inner = (pixels.end - pixels.start).abs() - 2 * px;
+            if inner <= 0 {
+                return (a, b);
+            }
+            let d = (b - a) * f64::from(px) / f64::from(inner);
+            (a - d, b + d)
+        };
+        self.x = pad(self.x, &self.pixel_range.0);
+        self.y = pad(self.y, &self.pixel_range.1);
+        self.update_cartesian();
+        self
+    }
+
+    /// Insets the pixel range the map is drawn on by `left`, `right`, `top` and `bottom`
+    /// pixels, like the label areas of a chart, so the graticule labels drawn outside of the
+    /// frame aren't clipped by the edges of the drawing area. The margins apply to the
+    /// current pixel range, a later [`resize`](Self::resize) replaces them. Margins leaving
+    /// no room for the map along an axis are ignored.
+    pub fn with_label_margins(mut self, left: i32, right: i32, top: i32, bottom: i32) -> Self {
+        let inset = |pixels: &SRange<i32>, low: i32, high: i32| {
+            let (a, b) = (pixels.start, pixels.end);
+            if (b - a).abs() - low - high <= 0 {
+                return a..b;
+            }
+            if a <= b {
+                a + low..b - high
+            } else {
+                a - high..b + low
+            }
+        };
+        self.pixel_range = (
+            inset(&self.pixel_range.0, left, right),
+            inset(&self.pixel_range.1, top, bottom),
+        );
+        self.update_cartesian();
+        self
+    }
+
+    /// Returns a ring covering the latitudes between `lat_min` and `lat_max` across the whole
+    /// longitude extent of the map, e.g. to shade the tropics. The band is clipped to the
+    /// extent and its edges are densified so they follow the projection, so the ring can be
+    /// filled directly with a [`Polygon`](crate::element::Polygon) on this coordinate.
+    pub fn lat_band_polygon(&self, lat_min: f64, lat_max: f64) -> Vec<Range> {
+        let (lon, lat) = self.extent();
+        let (lat_min, lat_max) = (lat_min.max(lat.0), lat_max.min(lat.1));
+        if lat_min >= lat_max {
+            return vec![];
+        }
+        geo_rectangle((lon.0, lon.1), (lat_min, lat_max), self.densify_step())
+    }
+
+    /// Returns a ring covering the longitudes between `lon_min` and `lon_max` across the whole
+    /// latitude extent of the map, e.g. to shade a time zone. See
+    /// [`lat_band_polygon`](Self::lat_band_polygon).
+    pub fn lon_band_polygon(&self, lon_min: f64, lon_max: f64) -> Vec<Range> {
+        let (lon, lat) = self.extent();
+        let (lon_min, lon_max) = (lon_min.max(lon.0), lon_max.min(lon.1));
+        if lon_min >= lon_max {
+            return vec![];
+        }
+        geo_rectangle((lon_min, lon_max), (lat.0, lat.1), self.densify_step())
+    }
+
+    /// Returns the geographic extent of the map as a GeoJSON `bbox` array,
+    /// `[west, south, east, north]`, e.g. to request a basemap service for the same view.
+    /// Missing ranges fall back to the projection's natural extent.
+    pub fn extent_geojson(&self) -> String {
+        let ((west, east), (south, north)) = self.extent();
+        format!("[{}, {}, {}, {}]", west, south, east, north)
+    }
+
+    /// Returns the geographic extent of the map as a WKT polygon, e.g. to query a spatial
+    /// database for the features in view. See [`extent_geojson`](Self::extent_geojson).
+    pub fn extent_wkt(&self) -> String {
+        let ((west, east), (south, north)) = self.extent();
+        format!(
+            "POLYGON (({w} {s}, {e} {s}, {e} {n}, {w} {n}, {w} {s}))",
+            w = west,
+            e = east,
+            s = south,
+            n = north
+        )
+    }
+
+    fn update_cartesian(&mut self) {
+        self.cartesian = Cartesian2d::new(
+            self.x.0..self.x.1,
+            self.y.0..self.y.1,
+            self.pixel_range.clone(),
+        );
+    }
+
+    /// The `(lon, lat)` extent of the map, falling back to the projection's natural extent
+    pub(crate) fn extent(&self) -> (Range, Range) {
+        let (lon, lat) = self.proj.natural_extent();
+        (self.lon.unwrap_or(lon), self.lat.unwrap_or(lat))
+    }
+
+    /// The projected window of the map
+    fn frame(&self) -> (Range, Range) {
+        let sorted = |(a, b): Range| (a.min(b), a.max(b));
+        (sorted(self.x), sorted(self.y))
+    }
+
+    /// Whether the projected point is within the window and, for bounded projections, the
+    /// outline of the map
+    fn within_frame(&self, (x, y): Range) -> bool {
+        let ((x0, x1), (y0, y1)) = self.frame();
+        if x < x0 || x > x1 || y < y0 || y > y1 {
+            return false;
+        }
+        self.proj
+            .outline()
+            .map_or(true, |outline| polygon_contains(&outline, (x, y)))
+    }
+
+    /// The projected width of a full turn of longitude along the equator, if the map
+    /// [wraps horizontally](Self::wrap_horizontal)
+    fn world_width(&self) -> Option<f64> {
+        if !self.wrap_horizontal {
+            return None;
+        }
+        let lon = self.proj.origin().0;
+        let west = self.proj.try_map((lon - 1.0, 0.0)).ok()?;
+        let east = self.proj.try_map((lon + 1.0, 0.0)).ok()?;
+        Some((east.0 - west.0).abs() * 180.0).filter(|w| w.is_finite() && *w > 0.0)
+    }
+
+    /// The projected point moved by whole world widths nearest to the middle of the window,
+    /// if the map wraps horizontally
+    fn wrapped(&self, (x, y): Range) -> Range {
+        match self.world_width() {
+            Some(width) => {
+                let ((x0, x1), _) = self.frame();
+                (x + width * (((x0 + x1) / 2.0 - x) / width).round(), y)
+            }
+            None => (x, y),
+        }
+    }
+
+    /// The densification step of geographic edges, infinite if disabled
+    fn densify_step(&self) -> f64 {
+        self.resolution.unwrap_or(f64::INFINITY)
+    }
+
+    /// Projects the `(lon, lat)` point to backend pixels, if it can be projected
+    fn project_point(&self, point: Range) -> Option<BackendCoord> {
+        self.project_f64(point)
+            .map(|v| self.cartesian.translate(&v))
+    }
+
+    /// Projects the `(lon, lat)` point, if it can be projected and faces the viewer
+    fn project_f64(&self, point: Range) -> Option<Range> {
+        let point = self.to_wgs84(point).ok()?;
+        if !self.proj.is_visible(point) {
+            return None;
+        }
+        match self.proj.try_map(point) {
+            Ok(v) if v.0.is_finite() && v.1.is_finite() => Some(v),
+            _ => None,
+        }
+    }
+
+    /// The `(lon, lat)` point given in the [angle unit](Self::with_angle_unit), in degrees,
+    /// its longitude [wrapped](Self::wrap_longitude) around the central meridian if asked to
+    fn in_degrees(&self, point: Range) -> Range {
+        let (lon, lat) = match self.angle_unit {
+            AngleUnit::Degrees => point,
+            AngleUnit::Radians => (point.0.to_degrees(), point.1.to_degrees()),
+        };
+        if !self.wrap_longitude {
+            return (lon, lat);
+        }
+        let central = self.proj.origin().0;
+        (central + normalize_lon(lon - central), lat)
+    }
+
+    fn to_wgs84(&self, point: Range) -> Result<Range, CoordError> {
+        match self.input_crs {
+            Some(ref transform) => Ok(transform.convert(point)?),
+            None => Ok(point),
+        }
+    }
+}
+
+impl LatLonCoord<Box<dyn ProjectionS>> {
+    /// Creates the map of the `lon`/`lat` extent with the projection
+    /// [`auto_projection`] picks for it, for data whose suitable projection isn't known:
+    /// polar stereographic near the poles, circumpolar caps included, Equal Earth for other
+    /// near-global extents, web Mercator for small regions and Lambert conformal for the
+    /// other mid-latitude ones. The projection
+    /// is boxed, so the map has the same type whichever is picked.
+    pub fn auto(
+        lon: Range,
+        lat: Range,
+        actual: (SRange<i32>, SRange<i32>),
+    ) -> Result<Self, CoordError> {
+        Self::try_new(Some(lon), Some(lat), actual, auto_projection(lon, lat))
+    }
+}
+
+impl<T: ProjectionS> CoordTranslate for LatLonCoord<T> {
+    type From = Range;
+    fn translate(&self, from: &Self::From) -> BackendCoord {
+        let from = self.to_wgs84(self.in_degrees(*from)).unwrap();
+        self.cartesian.translate(&self.wrapped(self.proj.map(from)))
+    }
+}
+
+/// Maps pixels back to `(lon, lat)` with the inverse projection, or `None` for pixels off
+/// the map. The result is on WGS84 even if an input CRS is set, as datum shifts are far
+/// below a pixel.
+impl<T: ProjectionS> ReverseCoordTranslate for LatLonCoord<T> {
+    fn reverse_translate(&self, input: BackendCoord) -> Option<Self::From> {
+        let projected = self.cartesian.reverse_translate(input)?;
+        if !self.within_frame(projected) {
+            return None;
+        }
+        match (self.proj.inverse(projected), self.angle_unit) {
+            (Ok(v), _) if !v.0.is_finite() || !v.1.is_finite() => None,
+            (Ok(v), AngleUnit::Degrees) => Some(v),
+            (Ok(v), AngleUnit::Radians) => Some((v.0.to_radians(), v.1.to_radians())),
+            _ => None,
+        }
+    }
+}
+
+/// The kinds of map projections
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Projection {
+    PlateCarree,
+    LambertConformal,
+    LambertCylindrical,
+    Mercator,
+    Orthographic,
+    PolarStereographic,
+    EqualEarth,
+    Mollweide,
+    RotatedPole,
+    LogAzimuthal,
+    Epsg,
+    /// A projection defined outside of this module
+    Custom,
+}
+
+impl Projection {
+    /// What the kind of projection preserves, see [`ProjectionS::properties`]. A rotated
+    /// pole grid is taken on its default lon/lat base.
+    pub fn properties(self) -> ProjectionProperties {
+        let none = ProjectionProperties::default();
+        match self {
+            Projection::Mercator
+            | Projection::LambertConformal
+            | Projection::PolarStereographic => ProjectionProperties {
+                conformal: true,
+                ..none
+            },
+            Projection::LambertCylindrical | Projection::EqualEarth | Projection::Mollweide => {
+                ProjectionProperties {
+                    equal_area: true,
+                    ..none
+                }
+            }
+            Projection::PlateCarree | Projection::RotatedPole => ProjectionProperties {
+                equidistant: true,
+                compromise: true,
+                ..none
+            },
+            Projection::LogAzimuthal => ProjectionProperties {
+                compromise: true,
+                ..none
+            },
+            Projection::Orthographic | Projection::Epsg | Projection::Custom => none,
+        }
+    }
+}
+
+/// The distortion class of a projection, what it preserves from the globe, e.g. to warn
+/// about comparing areas on a conformal map
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProjectionProperties {
+    /// Angles are preserved locally, so small shapes keep their shape
+    pub conformal: bool,
+    /// Areas are preserved, at the cost of the shapes
+    pub equal_area: bool,
+    /// Distances are preserved along some lines, e.g. the meridians of Plate Carrée
+    pub equidistant: bool,
+    /// Neither angles nor areas are preserved, the distortions are balanced for a good
+    /// overall look instead, like Plate Carrée
+    pub compromise: bool,
+}
+
+pub trait ProjectionS {
+    /// The projected bounding box of the lon/lat window, `None` ranges falling back to the
+    /// natural extent. By default, the window is sampled on a regular grid, or only its
+    /// corners are projected if [`bbox_is_corner_exact`](Self::bbox_is_corner_exact).
+    fn bbox(
+        &self,
+        x_ranged: Option<(f64, f64)>,
+        y_ranged: Option<(f64, f64)>,
+    ) -> Result<(Range, Range), CoordError> {
+        let (lon, lat) = self.natural_extent();
+        sampled_bbox(self, x_ranged.unwrap_or(lon), y_ranged.unwrap_or(lat))
+    }
+
+    /// Whether the projections of the south-west and north-east corners of any lon/lat
+    /// window bound it exactly, as with cylindrical projections, where meridians and
+    /// parallels are straight lines. The default [`bbox`](Self::bbox) then skips sampling.
+    fn bbox_is_corner_exact(&self) -> bool {
+        false
+    }
+
+    /// Adapts the projection to the `(lon, lat)` extent of the map, before
+    /// [`LatLonCoord`] projects it, e.g. the latitude a [`PlateCarree`] without a center
+    /// corrects its scale for. Does nothing by default.
+    fn fit_extent(&mut self, _lon: Range, _lat: Range) {}
+
+    fn map(&self, v: Range) -> Range;
+
+    /// Projects the `(lon, lat)` point like `map`, but reports points outside of the
+    /// projection's domain as an error instead of panicking.
+    fn try_map(&self, v: Range) -> Result<Range, CoordError> {
+        Ok(self.map(v))
+    }
+
+    /// Projects many `(lon, lat)` points at once, `None` for those `try_map` fails on.
+    /// Projections backed by PROJ do it in a single call into the library, much cheaper than
+    /// one call per point.
+    fn map_many(&self, points: &[Range]) -> Vec<Option<Range>> {
+        points.iter().map(|p| self.try_map(*p).ok()).collect()
+    }
+
+    /// The projected boundary of the projection's domain as a closed ring, for projections
+    /// whose valid area doesn't fill the whole plane (e.g. the disk of an orthographic globe).
+    fn outline(&self) -> Option<Vec<Range>> {
+        None
+    }
+
+    /// Maps the projected `(x, y)` point back to `(lon, lat)`. Projections without an
+    /// inverse return [`CoordError::NoInverse`].
+    fn inverse(&self, _v: Range) -> Result<Range, CoordError> {
+        Err(CoordError::NoInverse)
+    }
+
+    /// Whether the `(lon, lat)` point faces the viewer. Drawing helpers, like the graticule
+    /// and [`LatLonCoord::project_path`], cull the points that don't, e.g. the far side of an
+    /// orthographic globe.
+    fn is_visible(&self, _p: Range) -> bool {
+        true
+    }
+
+    /// The length of a projected unit on the ground, in meters. Defaults to `1.0`, as most
+    /// projections are in meters.
+    fn meters_per_unit(&self) -> f64 {
+        1.0
+    }
+
+    /// Whether points are projected by PROJ rather than by closed-form equations in Rust,
+    /// such as the ones the projections fall back to when PROJ fails, see e.g.
+    /// [`Mercator::with_fallback`]. Defaults to `true`.
+    fn uses_proj(&self) -> bool {
+        true
+    }
+
+    /// The kind of projection, mostly useful to inspect a boxed projection
+    fn kind(&self) -> Projection {
+        Projection::Custom
+    }
+
+    /// What the projection preserves, whether it is conformal, equal-area, equidistant or a
+    /// compromise. Defaults to the properties of its [`kind`](Self::kind), none for custom
+    /// projections.
+    fn properties(&self) -> ProjectionProperties {
+        self.kind().properties()
+    }
+
+    /// The `(lon, lat)` natural origin of the projection, its central meridian on its
+    /// latitude of origin
+    fn origin(&self) -> Range {
+        (0.0, 0.0)
+    }
+
+    /// The `(lon, lat)` bounds PROJ reports the projection as valid in, for the coordinate
+    /// reference systems of a registry such as [`Epsg`], to keep regional systems within
+    /// their region. Defaults to `None`, as projections built from PROJ strings have none.
+    fn area_of_use(&self) -> Option<(Range, Range)> {
+        None
+    }
+
+    /// The default `(lon, lat)` extent of the projection, used when no range is given
+    fn natural_extent(&self) -> (Range, Range) {
+        ((-180.0, 180.0), (-90.0, 90.0))
+    }
+
+    /// Returns the local area scale factor at the `(lon, lat)` point `p`, i.e. the ratio
+    /// between a small projected area and the same area on the WGS84 ellipsoid.
+    /// Equal-area projections return a value close to `1.0` everywhere.
+    fn area_scale_at(&self, p: Range) -> f64 {
+        let (d_lon, d_lat) = partial_derivatives(self, p);
+        let (m, n) = radii_of_curvature(p.1.to_radians());
+        (d_lon.0 * d_lat.1 - d_lon.1 * d_lat.0).abs() / (m * n * p.1.to_radians().cos())
+    }
+
+    /// Returns the linear scale factors `(h, k)` at the `(lon, lat)` point `p`, where `h` is
+    /// the scale along the meridian and `k` the scale along the parallel.
+    /// Conformal projections have `h == k`, equal-area projections have `h * k == 1`.
+    fn scale_factors_at(&self, p: Range) -> (f64, f64) {
+        let (d_lon, d_lat) = partial_derivatives(self, p);
+        let (m, n) = radii_of_curvature(p.1.to_radians());
+        let h = d_lat.0.hypot(d_lat.1) / m;
+        let k = d_lon.0.hypot(d_lon.1) / (n * p.1.to_radians().cos());
+        (h, k)
+    }
+
+    /// Returns the maximum angular deformation at the `(lon, lat)` point `p`, in degrees, i.e.
+    /// the largest change of an angle between two directions once projected. Conformal
+    /// projections return a value close to `0.0` everywhere.
+    fn angular_deformation_at(&self, p: Range) -> f64 {
+        let (d_lon, d_lat) = partial_derivatives(self, p);
+        let (h, k) = self.scale_factors_at(p);
+        // The sine of the angle between the projected meridian and parallel
+        let sin_theta = (d_lon.0 * d_lat.1 - d_lon.1 * d_lat.0).abs()
+            / (d_lon.0.hypot(d_lon.1) * d_lat.0.hypot(d_lat.1));
+        let a = (h * h + k * k + 2.0 * h * k * sin_theta).sqrt();
+        let b = (h * h + k * k - 2.0 * h * k * sin_theta).max(0.0).sqrt();
+        2.0 * (b / a).asin().to_degrees()
+    }
+}
+
+impl<P: ProjectionS + ?Sized> ProjectionS for Box<P> {
+    fn bbox(
+        &self,
+        x_ranged: Option<(f64, f64)>,
+        y_ranged: Option<(f64, f64)>,
+    ) -> Result<(Range, Range), CoordError> {
+        (**self).bbox(x_ranged, y_ranged)
+    }
+
+    fn bbox_is_corner_exact(&self) -> bool {
+        (**self).bbox_is_corner_exact()
+    }
+
+    fn fit_extent(&mut self, lon: Range, lat: Range) {
+        (**self).fit_extent(lon, lat)
+    }
+
+    fn map(&self, v: Range) -> Range {
+        (**self).map(v)
+    }
+
+    fn try_map(&self, v: Range) -> Result<Range, CoordError> {
+        (**self).try_map(v)
+    }
+
+    fn map_many(&self, points: &[Range]) -> Vec<Option<Range>> {
+        (**self).map_many(points)
+    }
+
+    fn inverse(&self, v: Range) -> Result<Range, CoordError> {
+        (**self).inverse(v)
+    }
+
+    fn outline(&self) -> Option<Vec<Range>> {
+        (**self).outline()
+    }
+
+    fn is_visible(&self, p: Range) -> bool {
+        (**self).is_visible(p)
+    }
+
+    fn origin(&self) -> Range {
+        (**self).origin()
+    }
+
+    fn meters_per_unit(&self) -> f64 {
+        (**self).meters_per_unit()
+    }
+
+    fn uses_proj(&self) -> bool {
+        (**self).uses_proj()
+    }
+
+    fn kind(&self) -> Projection {
+        (**self).kind()
+    }
+
+    fn properties(&self) -> ProjectionProperties {
+        (**self).properties()
+    }
+
+    fn area_of_use(&self) -> Option<(Range, Range)> {
+        (**self).area_of_use()
+    }
+
+    fn natural_extent(&self) -> (Range, Range) {
+        (**self).natural_extent()
+    }
+}
+
+/// Wraps the longitude `lon`, in degrees, into `[-180, 180)`, e.g. 350° into -10°
+pub fn normalize_lon(lon: f64) -> f64 {
+    (lon + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// Returns the projected `(x, y)` bounding box of the `lon`/`lat` window on `proj`, the
+/// one a [`LatLonCoord`] of that window maps to its pixels, without building the coord, e.g.
+/// to size a buffer. `None` ranges fall back to the projection's natural extent.
+pub fn projected_bounds<T: ProjectionS>(
+    proj: &T,
+    lon: Option<Range>,
+    lat: Option<Range>,
+) -> Result<(Range, Range), CoordError> {
+    proj.bbox(lon, lat)
+}
+
+const WGS84_A: f64 = 6378137.0;
+const METERS_PER_INCH: f64 = 0.0254;
+const WGS84_E2: f64 = 0.006_694_379_990_14;
+
+/// Step, in degrees, used for the numerical derivatives of a projection.
+const DERIVATIVE_STEP: f64 = 1e-5;
+
+/// The meridional and prime vertical radii of curvature of the WGS84 ellipsoid at `lat` (radians)
+fn radii_of_curvature(lat: f64) -> (f64, f64) {
+    let w = (1.0 - WGS84_E2 * lat.sin().powi(2)).sqrt();
+    (WGS84_A * (1.0 - WGS84_E2) / w.powi(3), WGS84_A / w)
+}
+
+/// Default maximum length, in degrees, of an edge of a geographic shape before it gets projected
+const DENSIFY_STEP: f64 = 1.0;
+
+/// Splits the geographic edge from `a` to `b` into pieces no longer than `step`,
+/// returning all the points but `b`
+fn densify(a: Range, b: Range, step: f64) -> impl Iterator<Item = Range> {
+    let n = ((b.0 - a.0).abs().max((b.1 - a.1).abs()) / step)
+        .ceil()
+        .max(1.0) as usize;
+    (0..n).map(move |i| {
+        let t = i as f64 / n as f64;
+        (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t)
+    })
+}
+
+/// The densified ring of the lon/lat rectangle, counter-clockwise from the south-west corner
+fn geo_rectangle(lon: Range, lat: Range, step: f64) -> Vec<Range> {
+    let corners = [
+        (lon.0, lat.0),
+        (lon.1, lat.0),
+        (lon.1, lat.1),
+        (lon.0, lat.1),
+    ];
+    (0..4)
+        .flat_map(|i| densify(corners[i], corners[(i + 1) % 4], step))
+        .collect()
+}
+
+/// Whether the point `p` is inside the `ring` polygon, using the even-odd rule
+fn polygon_contains(ring: &[Range], p: Range) -> bool {
+    let mut inside = false;
+    let mut j = ring.len().wrapping_sub(1);
+    for i in 0..ring.len() {
+        let (a, b) = (ring[i], ring[j]);
+        if (a.1 > p.1) != (b.1 > p.1) && p.0 < (b.0 - a.0) * (p.1 - a.1) / (b.1 - a.1) + a.0 {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+/// The number of intervals each side of the lon/lat window is split into to sample its bbox
+const BBOX_SAMPLES: usize = 20;
+
+/// The lon/lat points [`sampled_bbox`] projects for the window: its two corners if the
+/// projection is [corner exact](ProjectionS::bbox_is_corner_exact), else a regular grid
+fn bbox_samples<T: ProjectionS + ?Sized>(proj: &T, lon: Range, lat: Range) -> Vec<Range> {
+    if proj.bbox_is_corner_exact() {
+        return vec![(lon.0, lat.0), (lon.1, lat.1)];
+    }
+    let n = BBOX_SAMPLES as f64;
+    (0..=BBOX_SAMPLES)
+        .flat_map(|i| {
+            (0..=BBOX_SAMPLES).map(move |j| {
+                (
+                    lon.0 + (lon.1 - lon.0) * i as f64 / n,
+                    lat.0 + (lat.1 - lat.0) * j as f64 / n,
+                )
+            })
+        })
+        .collect()
+}
+
+/// Computes the projected bounding box of the lon/lat window by projecting a regular grid
+/// of sample points, for projections where the corners alone don't bound the extent, or
+/// the two corners only if the projection is [corner exact](ProjectionS::bbox_is_corner_exact).
+///
+/// Samples that can't be projected, like a pole under some projections or the far side of
+/// a globe, are skipped. An error is only returned if none of the samples can be projected.
+fn sampled_bbox<T: ProjectionS + ?Sized>(
+    proj: &T,
+    lon: Range,
+    lat: Range,
+) -> Result<(Range, Range), CoordError> {
+    if proj.bbox_is_corner_exact() {
+        let bl = proj.try_map((lon.0, lat.0))?;
+        let rt = proj.try_map((lon.1, lat.1))?;
+        return Ok(((bl.0, rt.0), (bl.1, rt.1)));
+    }
+    let mut x = (f64::INFINITY, f64::NEG_INFINITY);
+    let mut y = (f64::INFINITY, f64::NEG_INFINITY);
+    let mut error = None;
+    for p in bbox_samples(proj, lon, lat) {
+        match proj.try_map(p) {
+            Ok((px, py)) if px.is_finite() && py.is_finite() => {
+                x = (x.0.min(px), x.1.max(px));
+                y = (y.0.min(py), y.1.max(py));
+            }
+            Ok(_) => {}
+            Err(e) => error = Some(e),
+        }
+    }
+    match error {
+        Some(e) if x.0 > x.1 => Err(e),
+        _ => Ok((x, y)),
+    }
+}
+
+/// The projected derivatives `(d/dlon, d/dlat)` at `p`, per radian, by central differences
+fn partial_derivatives<T: ProjectionS + ?Sized>(proj: &T, p: Range) -> (Range, Range) {
+    let d = DERIVATIVE_STEP;
+    let diff = |a: Range, b: Range| {
+        let (a, b) = (proj.map(a), proj.map(b));
+        let s = (2.0 * d).to_radians();
+        ((b.0 - a.0) / s, (b.1 - a.1) / s)
+    };
+    (
+        diff((p.0 - d, p.1), (p.0 + d, p.1)),
+        diff((p.0, p.1 - d), (p.0, p.1 + d)),
+    )
+}
+
+/// Builds charts drawn on a map, see [`LatLonCoord`]
+pub trait GeoCoordTrait<'a, DB: DrawingBackend> {
+    /// Builds a chart whose coordinate is the map of the `lon`/`lat` extent drawn with
+    /// `proj`, so the series drawn on it get projected. `None` ranges fall back to the
+    /// projection's natural extent.
+    fn build_projected_coord<P: ProjectionS>(
+        &mut self,
+        lon: Option<Range>,
+        lat: Option<Range>,
+        proj: P,
+    ) -> Result<ChartContext<'a, DB, LatLonCoord<P>>, DrawingAreaErrorKind<DB::ErrorType>>;
+}
+
+impl<'a, 'b, DB: DrawingBackend> GeoCoordTrait<'a, DB> for ChartBuilder<'a, 'b, DB> {
+    fn build_projected_coord<P: ProjectionS>(
+        &mut self,
+        lon: Option<Range>,
+        lat: Option<Range>,
+        proj: P,
+    ) -> Result<ChartContext<'a, DB, LatLonCoord<P>>, DrawingAreaErrorKind<DB::ErrorType>> {
+        self.build_geo_coord(lon, lat, proj)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_mercator_scale_factors() {
+        let merc = Mercator::new().build();
+
+        for lat in [0.0, 30.0, 60.0, 75.0] {
+            let (h, k) = merc.scale_factors_at((10.0, lat));
+            assert!((h - k).abs() / k < 1e-4, "h = {}, k = {} at {}", h, k, lat);
+            assert!((merc.area_scale_at((10.0, lat)) - h * k).abs() / (h * k) < 1e-4);
+        }
+
+        let (_, k_eq) = merc.scale_factors_at((0.0, 0.0));
+        let (_, k_60) = merc.scale_factors_at((0.0, 60.0));
+        assert!((k_eq - 1.0).abs() < 1e-4);
+        assert!((k_60 - 2.0).abs() < 1e-2);
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_projection_properties() {
+        let merc = Mercator::new().build();
+        assert!(merc.properties().conformal && !merc.properties().equal_area);
+        assert!(merc.angular_deformation_at((10.0, 50.0)) < 1e-2);
+
+        let cea = LambertCylindrical::new().build();
+        assert!(cea.properties().equal_area && !cea.properties().conformal);
+        assert!((cea.area_scale_at((10.0, 50.0)) - 1.0).abs() < 1e-3);
+
+        let plate_carree = PlateCarree::geographic().properties();
+        assert!(plate_carree.equidistant && plate_carree.compromise);
+        assert!(LogAzimuthal::new(1000.0).properties().compromise);
+        let boxed: Box<dyn ProjectionS> = Box::new(Orthographic::new().build());
+        assert_eq!(boxed.properties(), ProjectionProperties::default());
+    }
+
+    #[test]
+    fn test_rotated_pole_properties() {
+        // The rotation of the sphere keeps the properties of the base projection
+        let rotated = RotatedPole::new(-162.0, 39.25);
+        assert!(rotated.properties().equidistant && rotated.properties().compromise);
+        let rotated = rotated.base("merc");
+        assert!(rotated.properties().conformal && !rotated.properties().equidistant);
+        assert!(
+            RotatedPole::new(0.0, 90.0)
+                .base("moll")
+                .properties()
+                .equal_area
+        );
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_distortion_at() {
+        let coord = LatLonCoord::new(None, None, (0..100, 0..100), Mercator::new().build());
+        let area: Vec<_> = (0..8)
+            .map(|i| coord.distortion_at((0.0, i as f64 * 10.0), DistortionMetric::Area))
+            .collect();
+        assert!((area[0] - 1.0).abs() < 1e-3);
+        assert!(area.windows(2).all(|w| w[0] < w[1]), "{:?}", area);
+        assert!(coord.distortion_at((0.0, 60.0), DistortionMetric::Angular) < 0.01);
+
+        let equal_area = LatLonCoord::new(
+            None,
+            None,
+            (0..100, 0..100),
+            LambertCylindrical::new().build(),
+        );
+        assert!((equal_area.distortion_at((0.0, 60.0), DistortionMetric::Area) - 1.0).abs() < 1e-3);
+        assert!(equal_area.distortion_at((0.0, 60.0), DistortionMetric::Angular) > 30.0);
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_build_projected_coord() {
+        use crate::prelude::{create_mocked_drawing_area, Circle, RED};
+
+        let drawing_area = create_mocked_drawing_area(400, 400, |m| {
+            m.check_draw_circle(|_, _, _, center, _| {
+                assert!((center.0 - 200).abs() <= 1 && (center.1 - 200).abs() <= 1)
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 1));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_projected_coord(
+                Some((-20.0, 20.0)),
+                Some((-20.0, 20.0)),
+                Orthographic::new().build(),
+            )
+            .unwrap();
+        chart
+            .draw_series(std::iter::once(Circle::new((0.0, 0.0), 3, RED)))
+            .unwrap();
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_input_crs() {
+        let new_coord = || {
+            LatLonCoord::new(
+                Some((-122.42, -122.38)),
+                Some((37.78, 37.82)),
+                (0..1000, 0..1000),
+                Mercator::new().build(),
+            )
+        };
+        let wgs84 = new_coord();
+        let nad27 = new_coord().with_input_crs(4267).unwrap();
+
+        let point = (-122.4, 37.8);
+        assert_ne!(wgs84.translate(&point), nad27.translate(&point));
+
+        assert!(new_coord().with_input_crs(0).is_err());
+
+        // An extra grid directory without grids keeps the same shift, whenever it is added
+        let searched = new_coord()
+            .add_grid_path(std::env::temp_dir())
+            .and_then(|coord| coord.with_input_crs(4267))
+            .unwrap();
+        assert_eq!(searched.translate(&point), nad27.translate(&point));
+        let searched_after = new_coord()
+            .with_input_crs(4267)
+            .and_then(|coord| coord.add_grid_path(std::env::temp_dir()))
+            .unwrap();
+        assert_eq!(searched_after.translate(&point), nad27.translate(&point));
+        assert!(searched_after.grid_paths.len() == 1 && searched_after.input_epsg == Some(4267));
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_is_visible() {
+        let coord = LatLonCoord::new(
+            Some((-10.0, 10.0)),
+            Some((40.0, 60.0)),
+            (0..100, 0..100),
+            Mercator::new().build(),
+        );
+        assert!(coord.is_visible((0.0, 50.0)));
+        assert!(!coord.is_visible((50.0, 50.0)));
+        assert!(!coord.is_visible((0.0, 70.0)));
+
+        let globe = LatLonCoord::new(
+            None,
+            None,
+            (0..100, 0..100),
+            Orthographic::new().center(0.0, 0.0).build(),
+        );
+        assert!(globe.is_visible((30.0, 30.0)));
+        assert!(!globe.is_visible((180.0, 0.0)));
+    }
+
+    #[test]
+    fn test_is_visible_like_translate() {
+        let map = |lon, lat| {
+            LatLonCoord::new(
+                Some(lon),
+                Some(lat),
+                (0..100, 0..100),
+                PlateCarree::geographic(),
+            )
+        };
+        let wrapped = map((-20.0, 20.0), (-10.0, 10.0)).wrap_longitude(true);
+        assert!(wrapped.is_visible((350.0, 0.0)));
+        assert_eq!(wrapped.translate(&(350.0, 0.0)), (25, 50));
+
+        let radians = map((-20.0, 20.0), (-10.0, 10.0)).with_angle_unit(AngleUnit::Radians);
+        assert!(radians.is_visible((0.1, 0.1)) && !radians.is_visible((1.0, 0.0)));
+
+        let pacific = map((170.0, 190.0), (-10.0, 10.0)).wrap_horizontal(true);
+        assert!(pacific.is_visible((-175.0, 0.0)));
+        assert_eq!(pacific.translate(&(-175.0, 0.0)), (75, 50));
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_reverse_translate() {
+        let coord = LatLonCoord::new(
+            Some((-10.0, 10.0)),
+            Some((40.0, 60.0)),
+            (0..100, 0..100),
+            Mercator::new().build(),
+        );
+        let (lon, lat) = coord
+            .reverse_translate(coord.translate(&(2.0, 50.0)))
+            .unwrap();
+        assert!((lon - 2.0).abs() < 0.2 && (lat - 50.0).abs() < 0.2);
+        assert_eq!(coord.reverse_translate((150, 50)), None);
+
+        let globe = LatLonCoord::new(
+            None,
+            None,
+            (0..100, 0..100),
+            Orthographic::new().center(0.0, 0.0).build(),
+        );
+        assert!(globe.reverse_translate((50, 50)).is_some());
+        assert_eq!(globe.reverse_translate((2, 2)), None);
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_extent_export() {
+        let coord = LatLonCoord::new(
+            Some((-10.5, 20.0)),
+            Some((35.0, 60.0)),
+            (0..100, 0..100),
+            Mercator::new().build(),
+        );
+        assert_eq!(coord.extent_geojson(), "[-10.5, 35, 20, 60]");
+        assert_eq!(
+            coord.extent_wkt(),
+            "POLYGON ((-10.5 35, 20 35, 20 60, -10.5 60, -10.5 35))"
+        );
+
+        let world = LatLonCoord::new(None, None, (0..100, 0..100), Mercator::new().build());
+        assert_eq!(world.extent_geojson(), "[-180, -80, 180, 84]");
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_resize() {
+        let mut coord = LatLonCoord::new(
+            Some((-10.0, 10.0)),
+            Some((-10.0, 10.0)),
+            (0..100, 0..100),
+            Mercator::new().build(),
+        );
+        assert_eq!(coord.translate(&(0.0, 0.0)), (50, 50));
+        coord.resize((0..200, 0..400));
+        assert_eq!(coord.translate(&(0.0, 0.0)), (100, 200));
+        assert_eq!(coord.translate(&(10.0, 10.0)), (200, 400));
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_zoom_to() {
+        let mut coord = LatLonCoord::new(
+            Some((-30.0, 30.0)),
+            Some((-20.0, 40.0)),
+            (0..600, 0..400),
+            Mercator::new().build(),
+        );
+        let focus = coord.translate(&(10.0, 20.0));
+        let zoomed = coord.zoom_to(focus, 2.0).unwrap();
+        assert_eq!(coord.lon, Some((-30.0, 30.0)));
+        coord = zoomed;
+        let (lon, lat) = (coord.lon.unwrap(), coord.lat.unwrap());
+        assert!((lon.1 - lon.0 - 30.0).abs() < 0.5 && (lat.1 - lat.0 - 30.0).abs() < 0.5);
+        let (x, y) = coord.reverse_translate(focus).unwrap();
+        assert!(
+            (x - 10.0).abs() < 0.5 && (y - 20.0).abs() < 0.5,
+            "{:?}",
+            (x, y)
+        );
+
+        // Zooming far out stops at the latitudes Mercator can show
+        let coord = coord.zoom_to(focus, 0.01).unwrap();
+        assert_eq!(coord.lat, Some((-80.0, 84.0)));
+
+        assert!(coord.zoom_to((-10, -10), 2.0).is_err());
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_pan_pixels() {
+        let mut coord = LatLonCoord::new(
+            Some((-30.0, 30.0)),
+            Some((-20.0, 20.0)),
+            (0..600, 0..400),
+            Mercator::new().build(),
+        );
+        // Dragging to the right by a tenth of the width shows 6 degrees further west
+        let panned = coord.pan_pixels(60, 0).unwrap();
+        assert_eq!(coord.lon, Some((-30.0, 30.0)));
+        coord = panned;
+        let lon = coord.lon.unwrap();
+        assert!((lon.0 + 36.0).abs() < 1e-6 && (lon.1 - 24.0).abs() < 1e-6);
+        assert_eq!(coord.lat, Some((-20.0, 20.0)));
+
+        // The world wraps round the antimeridian
+        for _ in 0..30 {
+            coord = coord.pan_pixels(600, 0).unwrap();
+        }
+        let lon = coord.lon.unwrap();
+        assert!(((lon.0 + lon.1) / 2.0).abs() <= 180.0);
+        assert!((lon.1 - lon.0 - 60.0).abs() < 1e-6);
+
+        // A long drag stops at the northern edge of the projection
+        let coord = coord.pan_pixels(0, -100_000).unwrap();
+        let lat = coord.lat.unwrap();
+        assert!((lat.1 - 84.0).abs() < 1e-9 && (lat.1 - lat.0 - 40.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pan_across_antimeridian() {
+        let coord = LatLonCoord::new(
+            Some((170.0, 190.0)),
+            Some((-10.0, 10.0)),
+            (0..400, 0..400),
+            Orthographic::new()
+                .center(180.0, 0.0)
+                .with_fallback(true)
+                .build(),
+        );
+        // The center of the map is inverse projected to -180° or 180°, the shift is a few
+        // degrees either way
+        for (dx, east) in [(-40, true), (40, false)] {
+            let (w, e) = coord.pan_pixels(dx, 0).unwrap().lon.unwrap();
+            let shift = normalize_lon((w + e) / 2.0 - 180.0);
+            assert!((e - w - 20.0).abs() < 1e-9);
+            assert!(((w + e) / 2.0).abs() <= 180.0);
+            assert!(shift.abs() < 5.0 && (shift > 0.0) == east, "{:?}", (w, e));
+        }
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_projected_margin() {
+        let new_coord = || {
+            LatLonCoord::new(
+                Some((-10.0, 10.0)),
+                Some((40.0, 60.0)),
+                (0..200, 0..200),
+                Mercator::new().build(),
+            )
+        };
+        let coord = new_coord();
+        let padded = new_coord().with_projected_margin(20);
+
+        assert_eq!(coord.translate(&(-10.0, 40.0)), (0, 0));
+        assert_eq!(padded.translate(&(-10.0, 40.0)), (20, 20));
+        assert_eq!(padded.translate(&(10.0, 60.0)), (180, 180));
+
+        // The window grows by 20px worth of projected units on each side
+        let unit = (coord.x.1 - coord.x.0) / 160.0;
+        assert!((padded.x.0 - (coord.x.0 - 20.0 * unit)).abs() < 1e-6);
+        assert!((padded.x.1 - (coord.x.1 + 20.0 * unit)).abs() < 1e-6);
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_from_cartesian() {
+        let (lon, lat) = (Some((-30.0, 30.0)), Some((-20.0, 40.0)));
+        let height = 0..399;
+        let pixels = (0..599, height.end..height.start);
+        let coord = LatLonCoord::new(lon, lat, pixels.clone(), Mercator::new().build());
+
+        let (x, y) = Mercator::new().build().bbox(lon, lat).unwrap();
+        let cartesian: Cartesian2d<RangedCoordf64, RangedCoordf64> =
+            Cartesian2d::new(x.0..x.1, y.0..y.1, pixels);
+        let mut shared = LatLonCoord::from_cartesian(cartesian, lon, lat, Mercator::new().build());
+        for p in [(0.0, 0.0), (-30.0, -20.0), (12.5, 33.0)].iter() {
+            assert_eq!(shared.translate(p), coord.translate(p));
+        }
+
+        // The orientation of the pixel range is kept on resize
+        shared.resize((0..599, height.end..height.start));
+        assert_eq!(shared.translate(&(-30.0, 40.0)), (0, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "the cartesian ranges don't match the projected extent")]
+    #[cfg(debug_assertions)]
+    fn test_from_mismatched_cartesian() {
+        let cartesian: Cartesian2d<RangedCoordf64, RangedCoordf64> =
+            Cartesian2d::new(0.0..1.0, 0.0..1.0, (0..100, 0..100));
+        LatLonCoord::from_cartesian(cartesian, None, None, PlateCarree::geographic());
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_angle_unit() {
+        let coord = || {
+            LatLonCoord::new(
+                Some((-180.0, 180.0)),
+                Some((-80.0, 80.0)),
+                (0..360, 0..240),
+                Mercator::new().build(),
+            )
+        };
+        let radians = coord().with_angle_unit(AngleUnit::Radians);
+        let quarter = std::f64::consts::FRAC_PI_2;
+        assert_eq!(
+            radians.translate(&(quarter, 0.5)),
+            coord().translate(&(90.0, 0.5f64.to_degrees()))
+        );
+
+        let p = radians.translate(&(quarter, 0.0));
+        let (lon, lat) = radians.reverse_translate(p).unwrap();
+        assert!((lon - quarter).abs() < 0.02 && lat.abs() < 0.02);
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_visible_bounds() {
+        let coord = LatLonCoord::new(
+            Some((-30.0, 30.0)),
+            Some((-20.0, 40.0)),
+            (0..600, 0..400),
+            Mercator::new().build(),
+        );
+        let (lon, lat) = coord.visible_bounds();
+        assert!((lon.0 + 30.0).abs() < 1e-6 && (lon.1 - 30.0).abs() < 1e-6);
+        assert!((lat.0 + 20.0).abs() < 1e-6 && (lat.1 - 40.0).abs() < 1e-6);
+
+        let arctic = LatLonCoord::new(
+            None,
+            Some((50.0, 90.0)),
+            (0..400, 0..400),
+            PolarStereographic::north().build(),
+        );
+        let (lon, lat) = arctic.visible_bounds();
+        assert_eq!(lon, (-180.0, 180.0));
+        assert_eq!(lat.1, 90.0);
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_projected_corners() {
+        let mercator = LatLonCoord::new(
+            Some((-10.0, 30.0)),
+            Some((35.0, 60.0)),
+            (0..100, 0..100),
+            Mercator::new().build(),
+        );
+        let [sw, se, ne, nw] = mercator.projected_corners();
+        assert!(sw.1 == se.1 && ne.1 == nw.1 && sw.0 == nw.0 && se.0 == ne.0);
+        assert!((sw.0 - mercator.x.0).abs() < 1e-6 && (se.0 - mercator.x.1).abs() < 1e-6);
+
+        // The parallels of a conic map are arcs, so its corners aren't a rectangle
+        let conic = LatLonCoord::new(
+            Some((-10.0, 30.0)),
+            Some((35.0, 60.0)),
+            (0..100, 0..100),
+            LambertConformal::new().center(10.0, 47.0).build(),
+        );
+        let [sw, se, ne, nw] = conic.projected_corners();
+        assert!((sw.1 - se.1).abs() < 1e-6 && (ne.0 - se.0) < 0.0 && (nw.0 - sw.0) > 0.0);
+    }
+
+    #[test]
+    fn test_translate_f64() {
+        let coord = LatLonCoord::new(
+            Some((0.0, 10.0)),
+            Some((0.0, 10.0)),
+            (0..10, 0..10),
+            PlateCarree::geographic().build(),
+        );
+        let (x, y) = coord.translate_f64(&(2.5, 7.25));
+        assert!((x - 2.5).abs() < 1e-9 && (y - 7.25).abs() < 1e-9);
+        assert_eq!(coord.translate(&(2.5, 7.25)), (2, 7));
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_projected_bounds() {
+        let (lon, lat) = (Some((-10.0, 30.0)), Some((35.0, 60.0)));
+        let proj = LambertConformal::new().center(10.0, 47.0).build();
+        let bounds = projected_bounds(&proj, lon, lat).unwrap();
+        let coord = LatLonCoord::new(lon, lat, (0..100, 0..100), proj);
+        assert_eq!(bounds, (coord.x, coord.y));
+
+        let unbuilt = Mercator::new();
+        assert!(matches!(
+            projected_bounds(&unbuilt, None, None),
+            Err(CoordError::Uninital)
+        ));
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_scale_denominator() {
+        let new_coord = |lon| {
+            LatLonCoord::new(
+                Some(lon),
+                Some((-10.0, 10.0)),
+                (0..500, 0..500),
+                Mercator::new().build(),
+            )
+        };
+        let near = new_coord((-10.0, 10.0)).scale_denominator(96.0);
+        let far = new_coord((-20.0, 20.0)).scale_denominator(96.0);
+        assert!((far / near - 2.0).abs() < 1e-9);
+        // 20 degrees of the equator over 500 pixels of 1/96 inch
+        assert!((near / 16_829_000.0 - 1.0).abs() < 1e-3, "{}", near);
+
+        // Degrees are converted to meters
+        let degrees = LatLonCoord::new(
+            Some((-10.0, 10.0)),
+            Some((-10.0, 10.0)),
+            (0..500, 0..500),
+            PlateCarree::geographic().build(),
+        );
+        assert!((degrees.scale_denominator(96.0) / near - 1.0).abs() < 1e-6);
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_debug_sample_points() {
+        let mercator = LatLonCoord::new(
+            Some((-10.0, 10.0)),
+            Some((40.0, 60.0)),
+            (0..400, 0..300),
+            Mercator::new().build(),
+        );
+        assert_eq!(
+            mercator.debug_sample_points(),
+            vec![(-10.0, 40.0), (10.0, 60.0)]
+        );
+
+        let conic = LatLonCoord::new(
+            Some((-20.0, 20.0)),
+            Some((30.0, 60.0)),
+            (0..400, 0..300),
+            Orthographic::new().center(0.0, 45.0).build(),
+        );
+        let samples = conic.debug_sample_points();
+        assert_eq!(samples.len(), 21 * 21);
+        // The samples bound the frame, so the extreme ones land on its edges
+        let pixels: Vec<_> = samples.iter().map(|p| conic.translate(p)).collect();
+        let near = |v: Option<i32>, expected: i32| (v.unwrap() - expected).abs() <= 1;
+        let xs = pixels.iter().map(|p| p.0);
+        let ys = pixels.iter().map(|p| p.1);
+        assert!(near(xs.clone().min(), 0) && near(xs.max(), 400));
+        assert!(near(ys.clone().min(), 0) && near(ys.max(), 300));
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_from_projected_extent() {
+        let lon = Some((-10.0, 10.0));
+        let lat = Some((40.0, 60.0));
+        let actual = (0..400, 300..0);
+        let (x, y) = Mercator::new().build().bbox(lon, lat).unwrap();
+        let projected =
+            LatLonCoord::from_projected_extent(x, y, actual.clone(), Mercator::new().build())
+                .unwrap();
+        let geographic = LatLonCoord::new(lon, lat, actual.clone(), Mercator::new().build());
+
+        assert_eq!((projected.lon, projected.lat), (None, None));
+        for p in [(-10.0, 40.0), (2.35, 48.85), (10.0, 60.0)].iter() {
+            assert_eq!(projected.translate(p), geographic.translate(p));
+        }
+        let ((w, e), (s, n)) = projected.visible_bounds();
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-6;
+        assert!(close(w, -10.0) && close(e, 10.0) && close(s, 40.0) && close(n, 60.0));
+
+        assert!(matches!(
+            LatLonCoord::from_projected_extent((x.1, x.0), y, actual, Mercator::new().build()),
+            Err(CoordError::UnorderedRange(_))
+        ));
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_project_iter() {
+        let coord = LatLonCoord::new(None, None, (0..400, 400..0), Orthographic::new().build());
+        // The far side of the globe and a missing value can't be projected
+        let points = vec![(0.0, 0.0), (150.0, 0.0), (10.0, f64::NAN), (30.0, 45.0)];
+        let streamed: Vec<_> = coord.project_iter(points.iter().copied()).collect();
+        assert_eq!(streamed, coord.translate_many(&points));
+        assert_eq!(
+            streamed,
+            vec![
+                Some(coord.translate(&(0.0, 0.0))),
+                None,
+                None,
+                Some(coord.translate(&(30.0, 45.0))),
+            ]
+        );
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_auto_coord() {
+        let world = LatLonCoord::auto((-180.0, 180.0), (-90.0, 90.0), (0..800, 400..0)).unwrap();
+        assert_eq!(world.proj.kind(), Projection::EqualEarth);
+
+        let usa = LatLonCoord::auto((-125.0, -65.0), (25.0, 50.0), (0..800, 400..0)).unwrap();
+        assert_eq!(usa.proj.kind(), Projection::LambertConformal);
+        // The extent fills the map
+        let (x, y) = usa.translate(&(-95.0, 37.5));
+        assert!(x > 300 && x < 500 && y > 100 && y < 300);
+    }
+
+    #[test]
+    fn test_auto_coord_arctic() {
+        // Every longitude north of 65°N, with the pole in the middle of the map
+        let arctic = LatLonCoord::auto((-180.0, 180.0), (65.0, 90.0), (0..400, 400..0)).unwrap();
+        assert_eq!(arctic.proj.kind(), Projection::PolarStereographic);
+        let (x, y) = arctic.translate(&(0.0, 90.0));
+        assert!((x - 200).abs() <= 1 && (y - 200).abs() <= 1, "{:?}", (x, y));
+        for lon in [-180.0, -90.0, 0.0, 90.0] {
+            assert!(arctic.is_visible((lon, 65.0)));
+        }
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_validate_data() {
+        let coord = LatLonCoord::new(
+            Some((170.0, 200.0)),
+            Some((-50.0, -30.0)),
+            (0..400, 0..300),
+            Mercator::new().build(),
+        );
+        let auckland = (174.76, -36.85);
+        let chatham = (-176.5, -44.0);
+        assert!(coord.validate_data(vec![auckland, chatham]).is_ok());
+
+        // Latitude first
+        let swapped = vec![auckland, (chatham.1, chatham.0)];
+        assert!(matches!(
+            coord.validate_data(swapped),
+            Err(CoordError::OutOfDomain((lon, lat))) if lon == -44.0 && lat == -176.5
+        ));
+        assert!(matches!(
+            coord.validate_data(vec![(2.35, 48.85)]),
+            Err(CoordError::OutOfExtent(_))
+        ));
+    }
+
+    #[test]
+    fn test_normalize_lon() {
+        assert_eq!(normalize_lon(350.0), -10.0);
+        assert_eq!(normalize_lon(360.0), 0.0);
+        assert_eq!(normalize_lon(0.0), 0.0);
+        assert_eq!(normalize_lon(180.0), -180.0);
+        assert_eq!(normalize_lon(-180.0), -180.0);
+        assert_eq!(normalize_lon(-190.0), 170.0);
+        assert_eq!(normalize_lon(179.5), 179.5);
+        assert_eq!(normalize_lon(725.0), 5.0);
+    }
+
+    #[test]
+    fn test_wrap_longitude() {
+        let world = |proj: PlateCarree, lon| {
+            LatLonCoord::new(Some(lon), Some((-90.0, 90.0)), (0..360, 0..180), proj)
+        };
+        let plain = world(PlateCarree::geographic(), (-180.0, 180.0));
+        let wrapped = world(PlateCarree::geographic(), (-180.0, 180.0)).wrap_longitude(true);
+        assert_eq!(
+            wrapped.translate(&(350.0, 0.0)),
+            plain.translate(&(-10.0, 0.0))
+        );
+        assert_ne!(
+            plain.translate(&(350.0, 0.0)),
+            plain.translate(&(-10.0, 0.0))
+        );
+        assert_eq!(
+            wrapped.translate(&(-10.0, 0.0)),
+            plain.translate(&(-10.0, 0.0))
+        );
+
+        // Around the antimeridian, the turn follows the central meridian
+        let pacific =
+            world(PlateCarree::geographic().center(180.0, 0.0), (0.0, 360.0)).wrap_longitude(true);
+        assert_eq!(
+            pacific.translate(&(-10.0, 0.0)),
+            pacific.translate(&(350.0, 0.0))
+        );
+        assert_eq!(pacific.translate(&(-170.0, 0.0)), (190, 90));
+        assert_eq!(pacific.translate(&(10.0, 0.0)), (10, 90));
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_projected_area() {
+        let cell = |lat: f64| vec![(0.0, lat), (1.0, lat), (1.0, lat + 1.0), (0.0, lat + 1.0)];
+        fn new_coord<P: ProjectionS>(proj: P) -> LatLonCoord<P> {
+            LatLonCoord::new(None, None, (0..400, 0..300), proj)
+        }
+        let equal_earth = new_coord(EqualEarth::new().build().unwrap());
+        let mercator = new_coord(Mercator::new().build());
+        let degrees = new_coord(PlateCarree::geographic().build());
+
+        for lat in [0.0, 30.0, 60.0].iter() {
+            let ring = cell(*lat);
+            let reference = geographic_area(&ring);
+            let ratio = equal_earth.projected_area(&ring) / reference;
+            assert!((ratio - 1.0).abs() < 0.01, "{} at {}", ratio, lat);
+            let reversed: Vec<_> = ring.iter().rev().copied().collect();
+            assert_eq!(
+                equal_earth.projected_area(&ring),
+                equal_earth.projected_area(&reversed)
+            );
+        }
+        // Mercator quadruples areas at 60°
+        let ratio = mercator.projected_area(&cell(60.0)) / geographic_area(&cell(60.0));
+        assert!((ratio - 4.0).abs() < 0.2, "{}", ratio);
+        // Degrees are converted to meters
+        let ratio = degrees.projected_area(&cell(0.0)) / geographic_area(&cell(0.0));
+        assert!((ratio - 1.0).abs() < 0.01, "{}", ratio);
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_label_margins() {
+        let new_coord = || {
+            LatLonCoord::new(
+                Some((-10.0, 10.0)),
+                Some((40.0, 60.0)),
+                (0..400, 299..0),
+                Mercator::new().build(),
+            )
+        };
+        let plain = new_coord();
+        assert_eq!(plain.translate(&(-10.0, 40.0)), (0, 299));
+        assert_eq!(plain.translate(&(10.0, 60.0)), (400, 0));
+
+        let inset = new_coord().with_label_margins(40, 10, 5, 25);
+        assert_eq!(inset.translate(&(-10.0, 40.0)), (40, 274));
+        assert_eq!(inset.translate(&(10.0, 60.0)), (390, 5));
+        // The central meridian moves with the unequal side margins
+        assert_eq!(plain.translate(&(0.0, 50.0)).0, 200);
+        assert_eq!(inset.translate(&(0.0, 50.0)).0, 215);
+
+        // Too wide to fit, the horizontal margins are ignored
+        let squeezed = new_coord().with_label_margins(300, 300, 0, 0);
+        assert_eq!(squeezed.translate(&(10.0, 60.0)), (400, 0));
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_covers_globe() {
+        let new_coord =
+            |lon, lat| LatLonCoord::new(lon, lat, (0..100, 0..100), Mercator::new().build());
+        assert!(new_coord(None, None).covers_globe());
+        assert!(new_coord(Some((-180.0, 180.0)), Some((-80.0, 84.0))).covers_globe());
+        assert!(new_coord(Some((0.0, 360.0)), None).covers_globe());
+        assert!(!new_coord(Some((-170.0, 170.0)), None).covers_globe());
+        assert!(!new_coord(None, Some((-60.0, 60.0))).covers_globe());
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_origin_pixel() {
+        let coord = LatLonCoord::new(
+            Some((-30.0, 30.0)),
+            Some((-20.0, 40.0)),
+            (0..600, 0..400),
+            Mercator::new().build(),
+        );
+        assert_eq!(coord.origin_pixel().map(|p| p.0), Some(300));
+
+        let east = LatLonCoord::new(
+            Some((10.0, 30.0)),
+            Some((-20.0, 40.0)),
+            (0..600, 0..400),
+            Mercator::new().build(),
+        );
+        assert_eq!(east.origin_pixel(), None);
+
+        let grid = RotatedPole::new(-162.0, 39.25).build();
+        let (lon, lat) = grid.origin();
+        assert!((lon - 18.0).abs() < 1e-9 && (lat - 50.75).abs() < 1e-9);
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_corner_exact_bbox() {
+        // The same projection, but going through the sampling
+        struct Sampled(Mercator);
+        impl ProjectionS for Sampled {
+            fn map(&self, v: Range) -> Range {
+                self.0.map(v)
+            }
+        }
+
+        let mercator = Mercator::new().build();
+        assert!(mercator.bbox_is_corner_exact());
+        let window = (Some((-30.0, 60.0)), Some((-45.0, 70.0)));
+        let (x, y) = mercator.bbox(window.0, window.1).unwrap();
+        let (sx, sy) = Sampled(Mercator::new().build())
+            .bbox(window.0, window.1)
+            .unwrap();
+        assert!((x.0 - sx.0).abs() < 1e-6 && (x.1 - sx.1).abs() < 1e-6);
+        assert!((y.0 - sy.0).abs() < 1e-6 && (y.1 - sy.1).abs() < 1e-6);
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_sampled_bbox_skips_failures() {
+        let polar = PolarStereographic::north().build();
+        let (x, y) = polar.bbox(None, Some((-90.0, 90.0))).unwrap();
+        assert!(x.0.is_finite() && x.1.is_finite() && x.0 < x.1);
+        assert!(y.0.is_finite() && y.1.is_finite() && y.0 < y.1);
+
+        let globe = Orthographic::new().center(0.0, 0.0).build();
+        assert!(globe
+            .bbox(Some((-180.0, 180.0)), Some((-90.0, 90.0)))
+            .is_ok());
+        assert!(globe
+            .bbox(Some((160.0, 200.0)), Some((-10.0, 10.0)))
+            .is_err());
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_band_polygons() {
+        let coord = LatLonCoord::new(
+            Some((-30.0, 30.0)),
+            Some((-20.0, 60.0)),
+            (0..100, 0..100),
+            Mercator::new().build(),
+        );
+
+        let tropics = coord.lat_band_polygon(-23.5, 23.5);
+        assert!(tropics.len() > 4);
+        assert!(tropics.iter().all(|p| p.1 >= -20.0 && p.1 <= 23.5));
+        assert!(tropics.iter().any(|p| p.0 == -30.0) && tropics.iter().any(|p| p.0 == 30.0));
+
+        let zone = coord.lon_band_polygon(22.5, 37.5);
+        assert!(zone.iter().all(|p| p.0 >= 22.5 && p.0 <= 30.0));
+        assert!(zone.iter().any(|p| p.1 == -20.0) && zone.iter().any(|p| p.1 == 60.0));
+
+        assert!(coord.lat_band_polygon(66.5, 90.0).is_empty());
+    }
+}
@@ -0,0 +1,88 @@
+//! Stand-ins for the types of the `proj` crate when the `proj` feature is off. No
+//! transformation can be created, so the projections relying on PROJ report it, or fall back
+//! to their closed-form equations, while the ones computed in Rust keep working.
+use std::fmt;
+use std::path::Path;
+
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+#[error("PROJ isn't available, it requires the `proj` feature")]
+pub struct ProjCreateError;
+
+/// Never created, as there is no transformation to fail
+#[derive(Debug)]
+pub enum ProjError {}
+
+impl fmt::Display for ProjError {
+    fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {}
+    }
+}
+
+impl std::error::Error for ProjError {}
+
+pub struct Area {
+    pub west: f64,
+    pub south: f64,
+    pub east: f64,
+    pub north: f64,
+}
+
+/// Never created, see [`Proj::new`]
+pub enum Proj {}
+
+impl Proj {
+    pub fn new(_definition: &str) -> Result<Proj, ProjCreateError> {
+        Err(ProjCreateError)
+    }
+
+    pub fn new_known_crs(
+        _from: &str,
+        _to: &str,
+        _area: Option<Area>,
+    ) -> Result<Proj, ProjCreateError> {
+        Err(ProjCreateError)
+    }
+
+    pub fn area_of_use(&self) -> Result<(Option<Area>, Option<String>), ProjError> {
+        match *self {}
+    }
+
+    pub fn project(&self, _point: (f64, f64), _inverse: bool) -> Result<(f64, f64), ProjError> {
+        match *self {}
+    }
+
+    pub fn convert(&self, _point: (f64, f64)) -> Result<(f64, f64), ProjError> {
+        match *self {}
+    }
+
+    pub fn project_array<'a>(
+        &self,
+        _points: &'a mut [(f64, f64)],
+        _inverse: bool,
+    ) -> Result<&'a mut [(f64, f64)], ProjError> {
+        match *self {}
+    }
+}
+
+pub struct ProjBuilder;
+
+impl ProjBuilder {
+    pub fn new() -> Self {
+        ProjBuilder
+    }
+
+    pub fn set_search_paths<P: AsRef<Path>>(&mut self, _path: P) -> Result<(), ProjError> {
+        Ok(())
+    }
+
+    pub fn proj_known_crs(
+        self,
+        _from: &str,
+        _to: &str,
+        _area: Option<Area>,
+    ) -> Result<Proj, ProjCreateError> {
+        Err(ProjCreateError)
+    }
+}
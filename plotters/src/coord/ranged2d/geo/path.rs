@@ -0,0 +1,514 @@
+use super::clip::{clip_polygon, clip_polyline, split_self_intersections};
+use super::polygon_contains;
+use super::{densify, LatLonCoord, ProjectionS, Range};
+use crate::coord::CoordTranslate;
+use crate::element::{PathElement, Polygon};
+use crate::style::ShapeStyle;
+use plotters_backend::BackendCoord;
+
+/// Maximum recursion depth of the adaptive subdivision of an edge
+const MAX_SUBDIVISIONS: u32 = 16;
+
+/// How much the distances of the points of an outline to its center may vary, relative to
+/// the radius, for the outline to be taken as a circle
+const DISK_TOLERANCE: f64 = 1e-9;
+
+/// How projected polygons overlapping themselves are filled, see
+/// [`LatLonCoord::project_polygons`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FillRule {
+    /// The parts covered an even number of times are left out, as holes
+    EvenOdd,
+    /// The parts covered at least once are filled
+    NonZero,
+}
+
+impl<T: ProjectionS> LatLonCoord<T> {
+    /// Projects the `(lon, lat)` polyline into ready-to-draw path elements. Edges are
+    /// densified following the resolution of the coordinate (see
+    /// [`with_resolution`](Self::with_resolution)) and clipped to the frame of the map.
+    ///
+    /// A line leaving the frame, or crossing points that can't be projected, is split into
+    /// several pieces, hence the `Vec`. The elements are in backend pixels: draw them on
+    /// `chart.plotting_area().use_screen_coord()`.
+    pub fn project_path<S: Into<ShapeStyle>>(
+        &self,
+        points: &[Range],
+        style: S,
+    ) -> Vec<PathElement<BackendCoord>> {
+        let style = style.into();
+        self.projected_pieces(&self.densified(points, false))
+            .into_iter()
+            .map(|piece| PathElement::new(piece, style))
+            .collect()
+    }
+
+    /// Projects the closed `(lon, lat)` ring into a fillable polygon, densified like
+    /// [`project_path`](Self::project_path) and clipped to the frame of the map. Points of
+    /// the ring that can't be projected are left out. The polygon is in backend pixels.
+    ///
+    /// A ring enclosing a pole, like Antarctica, is stitched to the south or north edge of the
+    /// map where it crosses the antimeridian, so it fills up to the edge.
+    pub fn project_polygon<S: Into<ShapeStyle>>(
+        &self,
+        ring: &[Range],
+        style: S,
+    ) -> Polygon<BackendCoord> {
+        let clipped = clip_polygon(&self.projected_ring(ring), self.frame());
+        Polygon::new(self.to_pixels(&clipped), style)
+    }
+
+    /// Projects the closed `(lon, lat)` ring like [`project_polygon`](Self::project_polygon),
+    /// filled following `rule` where the projected ring overlaps itself, e.g. once wrapped
+    /// around the antimeridian. With [`FillRule::NonZero`], the ring is split at its
+    /// self-intersections into several polygons.
+    pub fn project_polygons<S: Into<ShapeStyle>>(
+        &self,
+        ring: &[Range],
+        style: S,
+        rule: FillRule,
+    ) -> Vec<Polygon<BackendCoord>> {
+        let style = style.into();
+        let projected = self.projected_ring(ring);
+        let loops = match rule {
+            FillRule::EvenOdd => vec![projected],
+            FillRule::NonZero => split_self_intersections(&projected),
+        };
+        loops
+            .iter()
+            .map(|l| clip_polygon(l, self.frame()))
+            .filter(|l| !l.is_empty())
+            .map(|l| Polygon::new(self.to_pixels(&l), style))
+            .collect()
+    }
+
+    /// Returns the boundary of the map, in backend pixels, as a closed ring: the outline of
+    /// bounded projections, such as the disk of a globe, clipped to the frame, or the frame
+    /// itself otherwise.
+    pub fn boundary(&self) -> Vec<BackendCoord> {
+        let ((x0, x1), (y0, y1)) = self.frame();
+        let frame = vec![(x0, y0), (x1, y0), (x1, y1), (x0, y1)];
+        self.to_pixels(&self.curved_boundary().unwrap_or(frame))
+    }
+
+    /// Returns the SVG path data, e.g. `M 0 50 L 50 0 ... Z`, of the [boundary](Self::boundary)
+    /// of the map, to wrap the map layers of an SVG document in a `<clipPath>` instead of
+    /// clipping every polygon. Maps whose boundary is the rectangular frame, which the
+    /// drawing area already clips to, give `None`.
+    pub fn boundary_svg_path(&self) -> Option<String> {
+        let ring = self.to_pixels(&self.curved_boundary()?);
+        let mut data = String::new();
+        for (i, (x, y)) in ring.iter().enumerate() {
+            let command = if i == 0 { "M" } else { " L" };
+            data.push_str(&format!("{} {} {}", command, x, y));
+        }
+        data.push_str(" Z");
+        Some(data)
+    }
+
+    /// The center and radius, in projected units, of the disk of the globe, for projections
+    /// whose outline is a circle, such as [`Orthographic`](super::Orthographic); `None` for
+    /// the other ones
+    pub fn globe_disk(&self) -> Option<(Range, f64)> {
+        let outline = self.proj.outline()?;
+        let n = outline.len() as f64;
+        let center = outline
+            .iter()
+            .fold((0.0, 0.0), |c, p| (c.0 + p.0 / n, c.1 + p.1 / n));
+        let radii = outline
+            .iter()
+            .map(|p| (p.0 - center.0).hypot(p.1 - center.1));
+        let (min, max) = radii.fold((f64::INFINITY, 0.0_f64), |(min, max), r| {
+            (min.min(r), max.max(r))
+        });
+        if outline.len() < 3 || max - min > DISK_TOLERANCE * max {
+            return None;
+        }
+        Some((center, max))
+    }
+
+    /// The projected outline of bounded projections clipped to the frame, if it doesn't
+    /// cover the whole frame
+    fn curved_boundary(&self) -> Option<Vec<Range>> {
+        let ((x0, x1), (y0, y1)) = self.frame();
+        let frame = [(x0, y0), (x1, y0), (x1, y1), (x0, y1)];
+        match self.proj.outline() {
+            Some(outline) if !frame.iter().all(|p| polygon_contains(&outline, *p)) => {
+                Some(clip_polygon(&outline, self.frame()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Projects the geographic edge from `a` to `b`, recursively subdividing it until the
+    /// projected midpoint of every piece is within `tolerance_px` pixels of its chord, so the
+    /// polyline follows the projected curve with as few points as possible. Edges that are
+    /// straight once projected keep their two points.
+    pub fn project_adaptive(&self, a: Range, b: Range, tolerance_px: f64) -> Vec<BackendCoord> {
+        self.adaptive_edge(a, b, tolerance_px)
+            .into_iter()
+            .chain(Some(b))
+            .filter_map(|p| self.project_point(p))
+            .collect()
+    }
+
+    /// Projects the already densified polyline into pieces in backend pixels, split at the
+    /// points that can't be projected and clipped to the frame
+    pub(super) fn projected_pieces(&self, points: &[Range]) -> Vec<Vec<BackendCoord>> {
+        self.clipped_pixels(&self.wrapped_runs(self.projected_runs(points)))
+    }
+
+    /// If the map wraps horizontally, the projected runs made continuous across the seam and
+    /// repeated every world width over the frame
+    fn wrapped_runs(&self, runs: Vec<Vec<Range>>) -> Vec<Vec<Range>> {
+        let width = match self.world_width() {
+            Some(width) => width,
+            None => return runs,
+        };
+        let ((x0, x1), _) = self.frame();
+        let mut copies = vec![];
+        for mut run in runs {
+            for i in 1..run.len() {
+                let jump = run[i].0 - run[i - 1].0;
+                run[i].0 -= width * (jump / width).round();
+            }
+            let (west, east) = run.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |b, p| {
+                (b.0.min(p.0), b.1.max(p.0))
+            });
+            let first = ((x0 - east) / width).ceil() as i64;
+            let last = ((x1 - west) / width).floor() as i64;
+            for k in first..=last {
+                let shift = k as f64 * width;
+                copies.push(run.iter().map(|p| (p.0 + shift, p.1)).collect());
+            }
+        }
+        copies
+    }
+
+    /// Clips the projected runs to the frame, into pieces in backend pixels
+    pub(super) fn clipped_pixels(&self, runs: &[Vec<Range>]) -> Vec<Vec<BackendCoord>> {
+        let mut pieces = vec![];
+        for run in runs {
+            pieces.extend(clip_polyline(run, self.frame()));
+        }
+        pieces
+            .into_iter()
+            .filter(|piece| piece.len() > 1)
+            .map(|piece| self.to_pixels(&piece))
+            .collect()
+    }
+
+    /// The projected closed ring, stitched to the map edge if it encloses a pole. Points that
+    /// can't be projected are left out.
+    fn projected_ring(&self, ring: &[Range]) -> Vec<Range> {
+        self.projected_runs(&self.densified(&self.stitched_to_pole(ring), true))
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    /// Where the ring around a pole crosses the antimeridian, routes it along the map edge on
+    /// the side of the pole instead of straight across the map
+    fn stitched_to_pole(&self, ring: &[Range]) -> Vec<Range> {
+        let n = ring.len();
+        let wrapped = |a: f64, b: f64| {
+            let d = b - a;
+            d - 360.0 * (d / 360.0).round()
+        };
+        let winding: f64 = (0..n)
+            .map(|i| wrapped(ring[i].0, ring[(i + 1) % n].0))
+            .sum();
+        if winding.abs() < 180.0 {
+            return ring.to_vec();
+        }
+
+        let (_, lat) = self.extent();
+        let south = ring.iter().map(|p| p.1).sum::<f64>() < 0.0;
+        let edge = if south {
+            lat.0.min(lat.1)
+        } else {
+            lat.0.max(lat.1)
+        };
+        let mut stitched = vec![];
+        for i in 0..n {
+            let (a, b) = (ring[i], ring[(i + 1) % n]);
+            stitched.push(a);
+            if (b.0 - a.0).abs() > 180.0 {
+                stitched.push((a.0, edge));
+                stitched.push((b.0, edge));
+            }
+        }
+        stitched
+    }
+
+    /// The `points` with every edge densified, including the closing edge if `closed`
+    pub(super) fn densified(&self, points: &[Range], closed: bool) -> Vec<Range> {
+        let n = if closed {
+            points.len()
+        } else {
+            points.len().saturating_sub(1)
+        };
+        let edge = |i: usize| {
+            let (a, b) = (points[i], points[(i + 1) % points.len()]);
+            match self.tolerance {
+                Some(tolerance) => self.adaptive_edge(a, b, tolerance),
+                None => densify(a, b, self.densify_step()).collect(),
+            }
+        };
+        let mut result: Vec<Range> = (0..n).flat_map(edge).collect();
+        if !closed {
+            result.extend(points.last());
+        }
+        result
+    }
+
+    /// The adaptively subdivided geographic edge from `a` to `b`, without `b`
+    fn adaptive_edge(&self, a: Range, b: Range, tolerance_px: f64) -> Vec<Range> {
+        let mut points = vec![a];
+        if let (Some(pa), Some(pb)) = (self.project_f64(a), self.project_f64(b)) {
+            self.subdivide(
+                (a, pa),
+                (b, pb),
+                tolerance_px,
+                MAX_SUBDIVISIONS,
+                &mut points,
+            );
+        }
+        points
+    }
+
+    /// Pushes the points strictly between `a` and `b`, given with their projections
+    fn subdivide(
+        &self,
+        (a, pa): (Range, Range),
+        (b, pb): (Range, Range),
+        tolerance_px: f64,
+        depth: u32,
+        points: &mut Vec<Range>,
+    ) {
+        let m = ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0);
+        let pm = match self.project_f64(m) {
+            Some(pm) => pm,
+            None => return,
+        };
+        if depth == 0 || self.chord_distance_px(pa, pb, pm) <= tolerance_px {
+            return;
+        }
+        self.subdivide((a, pa), (m, pm), tolerance_px, depth - 1, points);
+        points.push(m);
+        self.subdivide((m, pm), (b, pb), tolerance_px, depth - 1, points);
+    }
+
+    /// The distance, in pixels, between the projected point `p` and the chord from `a` to `b`
+    fn chord_distance_px(&self, a: Range, b: Range, p: Range) -> f64 {
+        let pixels = |range: std::ops::Range<i32>, (v0, v1): Range| {
+            f64::from(range.end - range.start) / (v1 - v0).abs()
+        };
+        let sx = pixels(self.cartesian.get_x_axis_pixel_range(), self.x);
+        let sy = pixels(self.cartesian.get_y_axis_pixel_range(), self.y);
+        let scale = |v: Range| (v.0 * sx, v.1 * sy);
+        let (a, b, p) = (scale(a), scale(b), scale(p));
+
+        let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+        let length = dx.hypot(dy);
+        if length == 0.0 {
+            return (p.0 - a.0).hypot(p.1 - a.1);
+        }
+        ((p.0 - a.0) * dy - (p.1 - a.1) * dx).abs() / length
+    }
+
+    /// Projects the points, splitting them into runs at the points that can't be projected
+    /// or face away
+    pub(super) fn projected_runs(&self, points: &[Range]) -> Vec<Vec<Range>> {
+        let mut runs = vec![vec![]];
+        for &p in points {
+            match self.project_f64(p) {
+                Some(v) => runs.last_mut().unwrap().push(v),
+                _ if runs.last().unwrap().is_empty() => {}
+                _ => runs.push(vec![]),
+            }
+        }
+        runs
+    }
+
+    fn to_pixels(&self, points: &[Range]) -> Vec<BackendCoord> {
+        points.iter().map(|p| self.cartesian.translate(p)).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use crate::element::PointCollection;
+    use std::borrow::Borrow;
+
+    fn points<'a, E>(element: &'a E) -> Vec<BackendCoord>
+    where
+        &'a E: PointCollection<'a, BackendCoord>,
+    {
+        element
+            .point_iter()
+            .into_iter()
+            .map(|p| *p.borrow())
+            .collect()
+    }
+
+    #[cfg(feature = "proj")]
+    fn coord() -> LatLonCoord<Mercator> {
+        LatLonCoord::new(
+            Some((-10.0, 10.0)),
+            Some((-10.0, 10.0)),
+            (0..100, 0..100),
+            Mercator::new().build(),
+        )
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_project_path() {
+        let coord = coord();
+        let path = coord.project_path(&[(-5.0, 0.0), (5.0, 0.0)], crate::style::BLACK);
+        assert_eq!(path.len(), 1);
+        // Densified every degree
+        assert_eq!(points(&path[0]).len(), 11);
+
+        let coarse = self::coord().with_resolution(None);
+        let path = coarse.project_path(&[(-5.0, 0.0), (5.0, 0.0)], crate::style::BLACK);
+        assert_eq!(points(&path[0]), vec![(25, 50), (75, 50)]);
+
+        // Leaves the frame through the east edge and comes back
+        let path =
+            coarse.project_path(&[(0.0, -5.0), (20.0, 0.0), (0.0, 5.0)], crate::style::BLACK);
+        assert_eq!(path.len(), 2);
+        assert!(path.iter().all(|p| points(p).iter().all(|p| p.0 <= 100)));
+    }
+
+    #[test]
+    fn test_wrap_horizontal() {
+        let new_coord = |lon| {
+            LatLonCoord::new(
+                Some(lon),
+                Some((-10.0, 10.0)),
+                (0..370, 0..20),
+                PlateCarree::geographic().build(),
+            )
+            .wrap_horizontal(true)
+        };
+
+        // A window over both seams shows the feature at 179°E on both sides
+        let both_seams = new_coord((-185.0, 185.0));
+        let path = both_seams.project_path(&[(179.0, -5.0), (179.0, 5.0)], crate::style::BLACK);
+        let mut xs: Vec<_> = path.iter().map(|p| points(p)[0].0).collect();
+        xs.sort_unstable();
+        assert_eq!(xs, vec![4, 364]);
+
+        // Past the antimeridian, 175°W is drawn east of 179°E
+        let pacific = new_coord((170.0, 190.0)).with_resolution(None);
+        assert!(pacific.translate(&(-175.0, 0.0)).0 > pacific.translate(&(179.0, 0.0)).0);
+        let path = pacific.project_path(&[(179.0, 0.0), (-175.0, 0.0)], crate::style::BLACK);
+        assert_eq!(path.len(), 1);
+        let line = points(&path[0]);
+        // 6 degrees east, not 354 west
+        assert!((line[1].0 - line[0].0 - 111).abs() <= 1, "{:?}", line);
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_project_adaptive() {
+        let merc = LatLonCoord::new(
+            Some((-180.0, 180.0)),
+            Some((-60.0, 60.0)),
+            (0..720, 0..480),
+            Mercator::new().build(),
+        );
+        assert_eq!(merc.project_adaptive((0.0, 0.0), (90.0, 0.0), 0.5).len(), 2);
+
+        let moll = LatLonCoord::new(None, None, (0..720, 0..360), Mollweide::new().build());
+        let meridian = moll.project_adaptive((150.0, -80.0), (150.0, 80.0), 0.5);
+        assert!(meridian.len() > 8, "{:?}", meridian);
+
+        let path = moll
+            .with_tolerance(Some(0.5))
+            .project_path(&[(150.0, -80.0), (150.0, 80.0)], crate::style::BLACK);
+        assert_eq!(points(&path[0]).len(), meridian.len());
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_boundary() {
+        assert_eq!(
+            coord().boundary(),
+            vec![(0, 0), (100, 0), (100, 100), (0, 100)]
+        );
+
+        let globe = LatLonCoord::new(None, None, (0..100, 0..100), Orthographic::new().build());
+        let disk = globe.boundary();
+        assert!(disk.len() > 100);
+        assert!(disk
+            .iter()
+            .all(|p| ((p.0 - 50).pow(2) + (p.1 - 50).pow(2)) as f64 <= 52.0f64.powi(2)));
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_boundary_svg_path() {
+        assert_eq!(coord().boundary_svg_path(), None);
+
+        let globe = LatLonCoord::new(None, None, (0..100, 0..100), Orthographic::new().build());
+        let data = globe.boundary_svg_path().unwrap();
+        let disk = globe.boundary();
+        assert!(data.starts_with(&format!("M {} {} L ", disk[0].0, disk[0].1)));
+        assert!(data.ends_with(" Z"));
+        assert_eq!(data.matches(" L ").count(), disk.len() - 1);
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_stitch_polar_polygon() {
+        let coord = LatLonCoord::new(
+            Some((-180.0, 180.0)),
+            Some((-80.0, 80.0)),
+            (0..360, 0..240),
+            Mercator::new().build(),
+        );
+        let south_edge = coord.translate(&(0.0, -80.0)).1;
+
+        // Around the south pole, from the west to the east
+        let antarctica: Vec<_> = (-6..=6).map(|i| (i as f64 * 30.0, -70.0)).collect();
+        let polygon = coord.project_polygon(&antarctica, crate::style::BLACK);
+        let points = points(&polygon);
+        assert!(points.contains(&(360, south_edge)) && points.contains(&(0, south_edge)));
+        // Nothing goes across the map above the coast
+        let coast = coord.translate(&(0.0, -70.0)).1;
+        assert!(points
+            .iter()
+            .all(|p| (p.1 - south_edge).abs() <= (coast - south_edge).abs()));
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_project_polygons() {
+        let coord = coord().with_resolution(None);
+        let bowtie = [(-5.0, -5.0), (5.0, 5.0), (5.0, -5.0), (-5.0, 5.0)];
+        let even_odd = coord.project_polygons(&bowtie, crate::style::BLACK, FillRule::EvenOdd);
+        assert_eq!(even_odd.len(), 1);
+        assert_eq!(points(&even_odd[0]).len(), 4);
+
+        let non_zero = coord.project_polygons(&bowtie, crate::style::BLACK, FillRule::NonZero);
+        assert_eq!(non_zero.len(), 2);
+        assert!(non_zero.iter().all(|p| points(p).contains(&(50, 50))));
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_project_polygon() {
+        let coord = coord().with_resolution(None);
+        let square = [(0.0, 0.0), (20.0, 0.0), (20.0, 20.0), (0.0, 20.0)];
+        let polygon = coord.project_polygon(&square, crate::style::BLACK);
+        let points = points(&polygon);
+        assert_eq!(points.len(), 4);
+        assert!(points
+            .iter()
+            .all(|p| p.0 >= 50 && p.0 <= 100 && p.1 >= 50 && p.1 <= 100));
+    }
+}
@@ -0,0 +1,2282 @@
+use super::geodesic::spherical_destination;
+use super::{
+    densify, sampled_bbox, spherical_bearing, spherical_distance, CoordError, Proj, Projection,
+    ProjectionProperties, ProjectionS, Range, DENSIFY_STEP, WGS84_A,
+};
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI, SQRT_2};
+
+pub struct Mercator {
+    web: bool,
+    spherical: bool,
+    fallback: bool,
+    central_lon: f64,
+    min_latitude: f64,
+    max_latitude: f64,
+
+    false_easting: f64,
+    false_northing: f64,
+    latitude_true_scale: f64,
+
+    proj_marker: Option<Proj>,
+}
+
+fn proj_string<'a>(vs: Vec<(&'a str, &'a str)>) -> String {
+    vs.into_iter()
+        .map(|(option, value)| format!("+{}={}", option, value))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Projects the `(lon, lat)` point, in degrees, with the PROJ transformation of a projection
+fn forward(proj_marker: &Option<Proj>, v: Range) -> Result<Range, CoordError> {
+    let _proj_transformer = proj_marker.as_ref().ok_or(CoordError::Uninital)?;
+    Ok(_proj_transformer.project((v.0.to_radians(), v.1.to_radians()), false)?)
+}
+
+/// Projects the `(lon, lat)` points in degrees with a single call into PROJ, or one by one
+/// if any of them fails
+fn forward_many(proj_marker: &Option<Proj>, points: &[Range]) -> Vec<Option<Range>> {
+    let _proj_transformer = match proj_marker {
+        Some(proj) => proj,
+        None => return vec![None; points.len()],
+    };
+    let mut radians: Vec<Range> = points
+        .iter()
+        .map(|p| (p.0.to_radians(), p.1.to_radians()))
+        .collect();
+    match _proj_transformer.project_array(&mut radians, false) {
+        Ok(projected) => projected.iter().map(|p| Some(*p)).collect(),
+        Err(_) => points
+            .iter()
+            .map(|p| forward(proj_marker, *p).ok())
+            .collect(),
+    }
+}
+
+/// Maps the projected `(x, y)` point back to `(lon, lat)` in degrees
+fn inverse(proj_marker: &Option<Proj>, v: Range) -> Result<Range, CoordError> {
+    let _proj_transformer = proj_marker.as_ref().ok_or(CoordError::Uninital)?;
+    let (lon, lat) = _proj_transformer.project(v, true)?;
+    Ok((lon.to_degrees(), lat.to_degrees()))
+}
+
+/// Whether the projections fall back to their closed-form equations unless told otherwise:
+/// only without the `proj` feature, where no PROJ transformation can be created
+const FALLBACK_BY_DEFAULT: bool = cfg!(not(feature = "proj"));
+
+/// Creates the PROJ transformation of the projection string `definition`, or returns `None`
+/// when that fails and the projection falls back to its closed-form equations. Panics on the
+/// failure otherwise.
+fn build_proj(definition: &str, fallback: bool) -> Option<Proj> {
+    match Proj::new(definition) {
+        Ok(proj) => Some(proj),
+        Err(_) if fallback => None,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+/// Fails with [`CoordError::Uninital`] until the projection is built, on PROJ or on its
+/// closed-form fallback
+fn check_built(proj_marker: &Option<Proj>, spherical: bool) -> Result<(), CoordError> {
+    if spherical || proj_marker.is_some() {
+        Ok(())
+    } else {
+        Err(CoordError::Uninital)
+    }
+}
+
+/// The longitude in degrees brought within ±180°, keeping ±180° as they are like PROJ does
+fn adjust_lon(lon: f64) -> f64 {
+    if lon.abs() <= 180.0 {
+        lon
+    } else {
+        lon - 360.0 * (lon / 360.0).round()
+    }
+}
+
+/// The longitude `lon` east of the `central` meridian, in radians within ±π
+fn lon_offset(lon: f64, central: f64) -> f64 {
+    adjust_lon(lon - central).to_radians()
+}
+
+impl Mercator {
+    pub fn new() -> Self {
+        Self {
+            web: false,
+            spherical: false,
+            fallback: FALLBACK_BY_DEFAULT,
+            central_lon: 0.0,
+            min_latitude: -80.0,
+            max_latitude: 84.0,
+            false_easting: 0.0,
+            false_northing: 0.0,
+            latitude_true_scale: 0.0,
+            proj_marker: None,
+        }
+    }
+
+    /// Creates the spherical "Web Mercator" (EPSG:3857) used by web map tile services,
+    /// limited to the usual ±85.0511° latitude band
+    pub fn web() -> Self {
+        Self {
+            web: true,
+            min_latitude: -85.0511,
+            max_latitude: 85.0511,
+            ..Self::new()
+        }
+    }
+
+    /// Creates a Mercator projection of the WGS84 sphere (radius `a`) computed in closed form
+    /// in Rust, without going through PROJ: cheaper to evaluate, and also available when the
+    /// crate is built without the `proj` feature, e.g. on wasm where the PROJ library can't be
+    /// linked. [`build`](Self::build) is a no-op for it.
+    pub fn spherical() -> Self {
+        Self {
+            spherical: true,
+            ..Self::new()
+        }
+    }
+
+    /// Falls back to the closed-form equations of [`spherical`](Self::spherical) if
+    /// [`build`](Self::build) can't create the PROJ transformation, e.g. when the crate is built
+    /// without the `proj` feature, instead of panicking. The map keeps rendering, with the
+    /// spherical approximation of the ellipsoid off by up to 0.7% northward. Defaults to off, or on
+    /// without the `proj` feature.
+    pub fn with_fallback(mut self, fallback: bool) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Clamps the `lat` range to the latitude band of the projection, as the poles are
+    /// projected at infinity. Returns the range as it is if it is within the band, else the
+    /// clamped range as a [`CoordError::Clamped`] warning, which [`LatLonCoord::new`] falls
+    /// back to.
+    ///
+    /// [`LatLonCoord::new`]: super::LatLonCoord::new
+    pub fn clamp_latitudes(&self, lat: Range) -> Result<Range, CoordError> {
+        let clamp = |v: f64| v.max(self.min_latitude).min(self.max_latitude);
+        let clamped = (clamp(lat.0), clamp(lat.1));
+        if clamped == lat {
+            Ok(lat)
+        } else {
+            Err(CoordError::Clamped(clamped))
+        }
+    }
+
+    /// The distance on the map, in meters, of one radian along the equator
+    fn spherical_scale(&self) -> f64 {
+        WGS84_A * self.latitude_true_scale.to_radians().cos()
+    }
+
+    pub fn build(mut self) -> Self {
+        if self.spherical {
+            return self;
+        }
+        let _central_lon = &self.central_lon.to_string();
+        let _false_easting = &self.false_easting.to_string();
+        let _false_northing = &self.false_northing.to_string();
+        let _latitude_true_scale = &self.latitude_true_scale.to_string();
+
+        let input = vec![
+            ("proj", if self.web { "webmerc" } else { "merc" }),
+            ("lon_0", _central_lon.as_str()),
+            ("lat_ts", _latitude_true_scale.as_str()),
+            ("x_0", _false_easting.as_str()),
+            ("y_0", _false_northing.as_str()),
+            ("units", "m"),
+        ];
+        let _proj_string = proj_string(input);
+
+        self.proj_marker = build_proj(_proj_string.as_str(), self.fallback);
+        self.spherical = self.proj_marker.is_none();
+
+        self
+    }
+}
+
+impl Default for Mercator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProjectionS for Mercator {
+    /// The projected bounding box of the window. A window reaching past the latitude band of
+    /// the projection, e.g. to the poles, fails with the [clamped](Mercator::clamp_latitudes)
+    /// band as a [`CoordError::Clamped`] warning.
+    fn bbox(
+        &self,
+        x_ranged: Option<(f64, f64)>,
+        y_ranged: Option<(f64, f64)>,
+    ) -> Result<(Range, Range), CoordError> {
+        let (lon, lat) = self.natural_extent();
+        let lat = self.clamp_latitudes(y_ranged.unwrap_or(lat))?;
+        sampled_bbox(self, x_ranged.unwrap_or(lon), lat)
+    }
+
+    fn bbox_is_corner_exact(&self) -> bool {
+        true
+    }
+
+    fn map(&self, v: Range) -> Range {
+        self.try_map(v).unwrap()
+    }
+
+    fn try_map(&self, v: Range) -> Result<Range, CoordError> {
+        if !self.spherical {
+            return forward(&self.proj_marker, v);
+        }
+        let k = self.spherical_scale();
+        Ok((
+            k * (v.0 - self.central_lon).to_radians() + self.false_easting,
+            k * (FRAC_PI_4 + v.1.to_radians() / 2.0).tan().ln() + self.false_northing,
+        ))
+    }
+
+    fn map_many(&self, points: &[Range]) -> Vec<Option<Range>> {
+        if self.spherical {
+            return points.iter().map(|p| self.try_map(*p).ok()).collect();
+        }
+        forward_many(&self.proj_marker, points)
+    }
+
+    fn inverse(&self, v: Range) -> Result<Range, CoordError> {
+        if !self.spherical {
+            return inverse(&self.proj_marker, v);
+        }
+        let k = self.spherical_scale();
+        let (x, y) = (
+            (v.0 - self.false_easting) / k,
+            (v.1 - self.false_northing) / k,
+        );
+        Ok((
+            x.to_degrees() + self.central_lon,
+            (2.0 * y.exp().atan() - FRAC_PI_2).to_degrees(),
+        ))
+    }
+
+    fn origin(&self) -> Range {
+        (self.central_lon, 0.0)
+    }
+
+    fn uses_proj(&self) -> bool {
+        !self.spherical
+    }
+
+    fn kind(&self) -> Projection {
+        Projection::Mercator
+    }
+
+    fn natural_extent(&self) -> (Range, Range) {
+        ((-180.0, 180.0), (self.min_latitude, self.max_latitude))
+    }
+}
+
+/// The orthographic projection, showing the globe as seen from an infinite distance
+/// above `(central_lon, central_lat)`. Only the near hemisphere can be projected.
+pub struct Orthographic {
+    central_lon: f64,
+    central_lat: f64,
+    radius: f64,
+    spherical: bool,
+    fallback: bool,
+
+    proj_marker: Option<Proj>,
+}
+
+impl Orthographic {
+    /// Creates an orthographic projection centered on `(0, 0)`
+    pub fn new() -> Self {
+        Self {
+            central_lon: 0.0,
+            central_lat: 0.0,
+            radius: WGS84_A,
+            spherical: false,
+            fallback: FALLBACK_BY_DEFAULT,
+            proj_marker: None,
+        }
+    }
+
+    /// Sets the point the globe is viewed from
+    pub fn center(mut self, lon: f64, lat: f64) -> Self {
+        self.central_lon = lon;
+        self.central_lat = lat;
+        self
+    }
+
+    /// Falls back to closed-form equations in Rust if [`build`](Self::build) can't create the PROJ
+    /// transformation, instead of panicking. They are exact, as the globe is a sphere either way.
+    /// Defaults to off, or on without the `proj` feature.
+    pub fn with_fallback(mut self, fallback: bool) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Creates the underlying PROJ transformation
+    pub fn build(mut self) -> Self {
+        let _central_lon = &self.central_lon.to_string();
+        let _central_lat = &self.central_lat.to_string();
+        let _radius = &self.radius.to_string();
+
+        let input = vec![
+            ("proj", "ortho"),
+            ("lon_0", _central_lon.as_str()),
+            ("lat_0", _central_lat.as_str()),
+            ("R", _radius.as_str()),
+            ("units", "m"),
+        ];
+        let _proj_string = proj_string(input);
+
+        self.proj_marker = build_proj(_proj_string.as_str(), self.fallback);
+        self.spherical = self.proj_marker.is_none();
+
+        self
+    }
+
+    fn spherical_map(&self, v: Range) -> Result<Range, CoordError> {
+        if !self.is_visible(v) {
+            return Err(CoordError::OutOfDomain(v));
+        }
+        let (lam, phi) = (lon_offset(v.0, self.central_lon), v.1.to_radians());
+        let phi_0 = self.central_lat.to_radians();
+        Ok((
+            self.radius * phi.cos() * lam.sin(),
+            self.radius * (phi_0.cos() * phi.sin() - phi_0.sin() * phi.cos() * lam.cos()),
+        ))
+    }
+
+    fn spherical_inverse(&self, v: Range) -> Result<Range, CoordError> {
+        let rho = v.0.hypot(v.1);
+        if rho > self.radius {
+            return Err(CoordError::OutOfDomain(v));
+        }
+        let phi_0 = self.central_lat.to_radians();
+        let (sin_c, cos_c) = (rho / self.radius).asin().sin_cos();
+        let lat = if rho == 0.0 {
+            phi_0
+        } else {
+            (cos_c * phi_0.sin() + v.1 * sin_c * phi_0.cos() / rho).asin()
+        };
+        let lon = (v.0 * sin_c).atan2(rho * cos_c * phi_0.cos() - v.1 * sin_c * phi_0.sin());
+        Ok((
+            adjust_lon(lon.to_degrees() + self.central_lon),
+            lat.to_degrees(),
+        ))
+    }
+}
+
+impl Default for Orthographic {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProjectionS for Orthographic {
+    fn bbox(
+        &self,
+        x_ranged: Option<(f64, f64)>,
+        y_ranged: Option<(f64, f64)>,
+    ) -> Result<(Range, Range), CoordError> {
+        check_built(&self.proj_marker, self.spherical)?;
+        if x_ranged.is_none() && y_ranged.is_none() {
+            return Ok(((-self.radius, self.radius), (-self.radius, self.radius)));
+        }
+        let (lon, lat) = self.natural_extent();
+        sampled_bbox(self, x_ranged.unwrap_or(lon), y_ranged.unwrap_or(lat))
+    }
+
+    fn map(&self, v: Range) -> Range {
+        self.try_map(v).unwrap()
+    }
+
+    fn try_map(&self, v: Range) -> Result<Range, CoordError> {
+        if self.spherical {
+            return self.spherical_map(v);
+        }
+        forward(&self.proj_marker, v)
+    }
+
+    fn map_many(&self, points: &[Range]) -> Vec<Option<Range>> {
+        if self.spherical {
+            return points.iter().map(|p| self.spherical_map(*p).ok()).collect();
+        }
+        forward_many(&self.proj_marker, points)
+    }
+
+    fn inverse(&self, v: Range) -> Result<Range, CoordError> {
+        if self.spherical {
+            return self.spherical_inverse(v);
+        }
+        inverse(&self.proj_marker, v)
+    }
+
+    fn origin(&self) -> Range {
+        (self.central_lon, self.central_lat)
+    }
+
+    fn uses_proj(&self) -> bool {
+        !self.spherical
+    }
+
+    fn kind(&self) -> Projection {
+        Projection::Orthographic
+    }
+
+    fn is_visible(&self, p: Range) -> bool {
+        // The cosine of the angular distance to the center of the globe
+        let (lon, lat) = (p.0.to_radians(), p.1.to_radians());
+        let (lon_0, lat_0) = (self.central_lon.to_radians(), self.central_lat.to_radians());
+        lat_0.sin() * lat.sin() + lat_0.cos() * lat.cos() * (lon - lon_0).cos() >= 0.0
+    }
+
+    fn outline(&self) -> Option<Vec<Range>> {
+        const SEGMENTS: usize = 180;
+        Some(
+            (0..SEGMENTS)
+                .map(|i| {
+                    let a = std::f64::consts::PI * 2.0 * i as f64 / SEGMENTS as f64;
+                    (self.radius * a.cos(), self.radius * a.sin())
+                })
+                .collect(),
+        )
+    }
+}
+
+/// The Lambert conformal conic projection, well suited to mid-latitude regions that
+/// are wider east-west than north-south
+pub struct LambertConformal {
+    central_lon: f64,
+    central_lat: f64,
+    standard_parallels: Range,
+    spherical: bool,
+    fallback: bool,
+
+    proj_marker: Option<Proj>,
+}
+
+impl LambertConformal {
+    /// Creates a Lambert conformal conic projection with standard parallels at 33° and 45°
+    pub fn new() -> Self {
+        Self {
+            central_lon: 0.0,
+            central_lat: 39.0,
+            standard_parallels: (33.0, 45.0),
+            spherical: false,
+            fallback: FALLBACK_BY_DEFAULT,
+            proj_marker: None,
+        }
+    }
+
+    /// Sets the origin of the projection
+    pub fn center(mut self, lon: f64, lat: f64) -> Self {
+        self.central_lon = lon;
+        self.central_lat = lat;
+        self
+    }
+
+    /// Sets the two parallels along which the scale is true
+    pub fn standard_parallels(mut self, lat_1: f64, lat_2: f64) -> Self {
+        self.standard_parallels = (lat_1, lat_2);
+        self
+    }
+
+    /// Draws the cone of the WGS84 sphere with equations computed in Rust if [`build`](Self::build)
+    /// can't create the PROJ transformation, instead of panicking; the parallels then sit up to
+    /// 0.5% away from their place on the ellipsoid. Defaults to off, or on without the `proj`
+    /// feature.
+    pub fn with_fallback(mut self, fallback: bool) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Creates the underlying PROJ transformation
+    pub fn build(mut self) -> Self {
+        let _central_lon = &self.central_lon.to_string();
+        let _central_lat = &self.central_lat.to_string();
+        let _lat_1 = &self.standard_parallels.0.to_string();
+        let _lat_2 = &self.standard_parallels.1.to_string();
+
+        let input = vec![
+            ("proj", "lcc"),
+            ("lon_0", _central_lon.as_str()),
+            ("lat_0", _central_lat.as_str()),
+            ("lat_1", _lat_1.as_str()),
+            ("lat_2", _lat_2.as_str()),
+            ("units", "m"),
+        ];
+        let _proj_string = proj_string(input);
+
+        self.proj_marker = build_proj(_proj_string.as_str(), self.fallback);
+        self.spherical = self.proj_marker.is_none();
+
+        self
+    }
+
+    /// The constant `n` of the cone, the radius `R·F` of the equator over `tan^n(π/4)`, and
+    /// the radius `ρ0` of the origin, of the spherical equations
+    fn cone(&self) -> (f64, f64, f64) {
+        let t = |lat: f64| (FRAC_PI_4 + lat.to_radians() / 2.0).tan();
+        let (lat_1, lat_2) = self.standard_parallels;
+        let n = if (lat_1 - lat_2).abs() < 1e-10 {
+            lat_1.to_radians().sin()
+        } else {
+            (lat_1.to_radians().cos() / lat_2.to_radians().cos()).ln() / (t(lat_2) / t(lat_1)).ln()
+        };
+        let f = WGS84_A * lat_1.to_radians().cos() * t(lat_1).powf(n) / n;
+        (n, f, f / t(self.central_lat).powf(n))
+    }
+
+    fn spherical_map(&self, v: Range) -> Result<Range, CoordError> {
+        let (n, f, rho_0) = self.cone();
+        // The pole opposite to the apex of the cone is at infinity
+        let rho = f / (FRAC_PI_4 + v.1.to_radians() / 2.0).tan().powf(n);
+        if !rho.is_finite() {
+            return Err(CoordError::OutOfDomain(v));
+        }
+        let theta = n * lon_offset(v.0, self.central_lon);
+        Ok((rho * theta.sin(), rho_0 - rho * theta.cos()))
+    }
+
+    fn spherical_inverse(&self, v: Range) -> Result<Range, CoordError> {
+        let (n, f, rho_0) = self.cone();
+        let (x, y) = (n.signum() * v.0, n.signum() * (rho_0 - v.1));
+        let rho = n.signum() * x.hypot(y);
+        let lat = if rho == 0.0 {
+            90f64.copysign(n)
+        } else {
+            (2.0 * (f / rho).powf(1.0 / n).atan() - FRAC_PI_2).to_degrees()
+        };
+        let lon = (x.atan2(y) / n).to_degrees() + self.central_lon;
+        Ok((adjust_lon(lon), lat))
+    }
+}
+
+impl Default for LambertConformal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProjectionS for LambertConformal {
+    fn bbox(
+        &self,
+        x_ranged: Option<(f64, f64)>,
+        y_ranged: Option<(f64, f64)>,
+    ) -> Result<(Range, Range), CoordError> {
+        check_built(&self.proj_marker, self.spherical)?;
+        let (lon, lat) = self.natural_extent();
+        sampled_bbox(self, x_ranged.unwrap_or(lon), y_ranged.unwrap_or(lat))
+    }
+
+    fn map(&self, v: Range) -> Range {
+        self.try_map(v).unwrap()
+    }
+
+    fn try_map(&self, v: Range) -> Result<Range, CoordError> {
+        if self.spherical {
+            return self.spherical_map(v);
+        }
+        forward(&self.proj_marker, v)
+    }
+
+    fn map_many(&self, points: &[Range]) -> Vec<Option<Range>> {
+        if self.spherical {
+            return points.iter().map(|p| self.spherical_map(*p).ok()).collect();
+        }
+        forward_many(&self.proj_marker, points)
+    }
+
+    fn inverse(&self, v: Range) -> Result<Range, CoordError> {
+        if self.spherical {
+            return self.spherical_inverse(v);
+        }
+        inverse(&self.proj_marker, v)
+    }
+
+    fn origin(&self) -> Range {
+        (self.central_lon, self.central_lat)
+    }
+
+    fn uses_proj(&self) -> bool {
+        !self.spherical
+    }
+
+    fn kind(&self) -> Projection {
+        Projection::LambertConformal
+    }
+
+    fn natural_extent(&self) -> (Range, Range) {
+        // The hemisphere the cone is tangent to, stopping short of the opposite pole
+        if self.standard_parallels.0 + self.standard_parallels.1 >= 0.0 {
+            ((-180.0, 180.0), (0.0, 84.0))
+        } else {
+            ((-180.0, 180.0), (-84.0, 0.0))
+        }
+    }
+}
+
+/// The polar stereographic projection, centered on either the north or the south pole
+pub struct PolarStereographic {
+    north: bool,
+    central_lon: f64,
+    spherical: bool,
+    fallback: bool,
+
+    proj_marker: Option<Proj>,
+}
+
+impl PolarStereographic {
+    /// Creates a stereographic projection centered on the north pole
+    pub fn north() -> Self {
+        Self {
+            north: true,
+            central_lon: 0.0,
+            spherical: false,
+            fallback: FALLBACK_BY_DEFAULT,
+            proj_marker: None,
+        }
+    }
+
+    /// Creates a stereographic projection centered on the south pole
+    pub fn south() -> Self {
+        Self {
+            north: false,
+            ..Self::north()
+        }
+    }
+
+    /// Sets the meridian that points straight down (north) or up (south) from the pole
+    pub fn central_lon(mut self, lon: f64) -> Self {
+        self.central_lon = lon;
+        self
+    }
+
+    /// Projects the WGS84 sphere from the opposite pole in Rust if [`build`](Self::build) can't
+    /// create the PROJ transformation, instead of panicking, which moves the points by up to 0.7%
+    /// from their place on the ellipsoid. Defaults to off, or on without the `proj` feature.
+    pub fn with_fallback(mut self, fallback: bool) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Creates the underlying PROJ transformation
+    pub fn build(mut self) -> Self {
+        let _central_lon = &self.central_lon.to_string();
+
+        let input = vec![
+            ("proj", "stere"),
+            ("lat_0", if self.north { "90" } else { "-90" }),
+            ("lon_0", _central_lon.as_str()),
+            ("units", "m"),
+        ];
+        let _proj_string = proj_string(input);
+
+        self.proj_marker = build_proj(_proj_string.as_str(), self.fallback);
+        self.spherical = self.proj_marker.is_none();
+
+        self
+    }
+
+    fn spherical_map(&self, v: Range) -> Result<Range, CoordError> {
+        // The distance from the pole, the opposite pole being at infinity
+        let lat = if self.north { v.1 } else { -v.1 };
+        if lat <= -90.0 {
+            return Err(CoordError::OutOfDomain(v));
+        }
+        let rho = 2.0 * WGS84_A * (FRAC_PI_4 - lat.to_radians() / 2.0).tan();
+        let lam = lon_offset(v.0, self.central_lon);
+        if self.north {
+            Ok((rho * lam.sin(), -rho * lam.cos()))
+        } else {
+            Ok((rho * lam.sin(), rho * lam.cos()))
+        }
+    }
+
+    fn spherical_inverse(&self, v: Range) -> Result<Range, CoordError> {
+        let rho = v.0.hypot(v.1);
+        let colat = 2.0 * (rho / (2.0 * WGS84_A)).atan();
+        let (lat, lon) = if self.north {
+            (FRAC_PI_2 - colat, v.0.atan2(-v.1))
+        } else {
+            (colat - FRAC_PI_2, v.0.atan2(v.1))
+        };
+        let lon = if rho == 0.0 { 0.0 } else { lon.to_degrees() };
+        Ok((adjust_lon(lon + self.central_lon), lat.to_degrees()))
+    }
+}
+
+impl ProjectionS for PolarStereographic {
+    fn bbox(
+        &self,
+        x_ranged: Option<(f64, f64)>,
+        y_ranged: Option<(f64, f64)>,
+    ) -> Result<(Range, Range), CoordError> {
+        check_built(&self.proj_marker, self.spherical)?;
+        let (lon, lat) = self.natural_extent();
+        sampled_bbox(self, x_ranged.unwrap_or(lon), y_ranged.unwrap_or(lat))
+    }
+
+    fn map(&self, v: Range) -> Range {
+        self.try_map(v).unwrap()
+    }
+
+    fn try_map(&self, v: Range) -> Result<Range, CoordError> {
+        if self.spherical {
+            return self.spherical_map(v);
+        }
+        forward(&self.proj_marker, v)
+    }
+
+    fn map_many(&self, points: &[Range]) -> Vec<Option<Range>> {
+        if self.spherical {
+            return points.iter().map(|p| self.spherical_map(*p).ok()).collect();
+        }
+        forward_many(&self.proj_marker, points)
+    }
+
+    fn inverse(&self, v: Range) -> Result<Range, CoordError> {
+        if self.spherical {
+            return self.spherical_inverse(v);
+        }
+        inverse(&self.proj_marker, v)
+    }
+
+    fn origin(&self) -> Range {
+        (self.central_lon, if self.north { 90.0 } else { -90.0 })
+    }
+
+    fn uses_proj(&self) -> bool {
+        !self.spherical
+    }
+
+    fn kind(&self) -> Projection {
+        Projection::PolarStereographic
+    }
+
+    fn natural_extent(&self) -> (Range, Range) {
+        if self.north {
+            ((-180.0, 180.0), (0.0, 90.0))
+        } else {
+            ((-180.0, 180.0), (-90.0, 0.0))
+        }
+    }
+}
+
+/// The Equal Earth projection, an equal-area pseudocylindrical projection for world maps.
+///
+/// The projection was added in PROJ 5.2: with older versions, [`build`](Self::build)
+/// returns [`CoordError::Unavailable`].
+///
+/// The world is drawn as a flattened oval, about twice as wide as high, with the poles
+/// drawn as lines:
+///
+/// ```
+/// use plotters::coord::geo::{EqualEarth, ProjectionS};
+///
+/// let proj = EqualEarth::new().build().unwrap();
+/// let outline = proj.outline().unwrap();
+/// let (x, y) = proj.bbox(None, None).unwrap();
+/// let aspect = (x.1 - x.0) / (y.1 - y.0);
+/// assert!(aspect > 2.0 && aspect < 2.1);
+///
+/// // The top edge of the oval is the north pole, a bit more than half as wide as the equator
+/// let pole = outline.iter().filter(|p| (p.1 - y.1).abs() < 1.0).map(|p| p.0.abs());
+/// let pole_width = 2.0 * pole.fold(0.0, f64::max);
+/// assert!(pole_width > 0.5 * (x.1 - x.0) && pole_width < 0.7 * (x.1 - x.0));
+/// ```
+pub struct EqualEarth {
+    central_lon: f64,
+    spherical: bool,
+    fallback: bool,
+
+    proj_marker: Option<Proj>,
+}
+
+impl EqualEarth {
+    /// Creates an Equal Earth projection centered on the prime meridian
+    pub fn new() -> Self {
+        Self {
+            central_lon: 0.0,
+            spherical: false,
+            fallback: FALLBACK_BY_DEFAULT,
+            proj_marker: None,
+        }
+    }
+
+    /// Sets the central meridian of the map
+    pub fn central_lon(mut self, lon: f64) -> Self {
+        self.central_lon = lon;
+        self
+    }
+
+    /// Evaluates the Equal Earth polynomial on the WGS84 sphere in Rust if [`build`](Self::build)
+    /// can't create the PROJ transformation, e.g. with PROJ older than 5.2, instead of returning
+    /// [`CoordError::Unavailable`]. The map is then up to 0.2% off the ellipsoidal one. Defaults to
+    /// off, or on without the `proj` feature.
+    pub fn with_fallback(mut self, fallback: bool) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Creates the underlying PROJ transformation, failing if the PROJ library is too old
+    /// to provide it
+    pub fn build(mut self) -> Result<Self, CoordError> {
+        let _central_lon = &self.central_lon.to_string();
+
+        let input = vec![
+            ("proj", "eqearth"),
+            ("lon_0", _central_lon.as_str()),
+            ("units", "m"),
+        ];
+        let _proj_string = proj_string(input);
+
+        match Proj::new(_proj_string.as_str()) {
+            Ok(proj) => self.proj_marker = Some(proj),
+            Err(_) if self.fallback => self.spherical = true,
+            Err(source) => {
+                return Err(CoordError::Unavailable {
+                    projection: "Equal Earth",
+                    requires: "PROJ 5.2",
+                    source,
+                })
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// The polynomial of the parametric latitude `θ` giving `y`, and its derivative
+    fn polynomial(theta: f64) -> (f64, f64) {
+        const A: [f64; 4] = [1.340264, -0.081106, 0.000893, 0.003796];
+        let (t2, t6) = (theta * theta, theta.powi(6));
+        (
+            theta * (A[0] + A[1] * t2 + t6 * (A[2] + A[3] * t2)),
+            A[0] + 3.0 * A[1] * t2 + t6 * (7.0 * A[2] + 9.0 * A[3] * t2),
+        )
+    }
+
+    fn spherical_map(&self, v: Range) -> Result<Range, CoordError> {
+        let m = 3f64.sqrt() / 2.0;
+        let theta = (m * v.1.to_radians().sin()).asin();
+        let (y, dy) = Self::polynomial(theta);
+        let lam = lon_offset(v.0, self.central_lon);
+        Ok((WGS84_A * lam * theta.cos() / (m * dy), WGS84_A * y))
+    }
+
+    fn spherical_inverse(&self, v: Range) -> Result<Range, CoordError> {
+        let m = 3f64.sqrt() / 2.0;
+        let y = v.1 / WGS84_A;
+        // Newton's method, from the equator
+        let mut theta = y;
+        for _ in 0..12 {
+            let (f, df) = Self::polynomial(theta);
+            let delta = (f - y) / df;
+            theta -= delta;
+            if delta.abs() < 1e-12 {
+                break;
+            }
+        }
+        let sin_lat = theta.sin() / m;
+        let lam = m * v.0 / WGS84_A * Self::polynomial(theta).1 / theta.cos();
+        if sin_lat.abs() > 1.0 || lam.abs() > PI + 1e-9 {
+            return Err(CoordError::OutOfDomain(v));
+        }
+        Ok((
+            adjust_lon(lam.to_degrees() + self.central_lon),
+            sin_lat.asin().to_degrees(),
+        ))
+    }
+}
+
+impl Default for EqualEarth {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProjectionS for EqualEarth {
+    fn bbox(
+        &self,
+        x_ranged: Option<(f64, f64)>,
+        y_ranged: Option<(f64, f64)>,
+    ) -> Result<(Range, Range), CoordError> {
+        check_built(&self.proj_marker, self.spherical)?;
+        let (lon, lat) = self.natural_extent();
+        sampled_bbox(self, x_ranged.unwrap_or(lon), y_ranged.unwrap_or(lat))
+    }
+
+    fn map(&self, v: Range) -> Range {
+        self.try_map(v).unwrap()
+    }
+
+    fn try_map(&self, v: Range) -> Result<Range, CoordError> {
+        if self.spherical {
+            return self.spherical_map(v);
+        }
+        forward(&self.proj_marker, v)
+    }
+
+    fn map_many(&self, points: &[Range]) -> Vec<Option<Range>> {
+        if self.spherical {
+            return points.iter().map(|p| self.spherical_map(*p).ok()).collect();
+        }
+        forward_many(&self.proj_marker, points)
+    }
+
+    fn inverse(&self, v: Range) -> Result<Range, CoordError> {
+        if self.spherical {
+            return self.spherical_inverse(v);
+        }
+        inverse(&self.proj_marker, v)
+    }
+
+    fn origin(&self) -> Range {
+        (self.central_lon, 0.0)
+    }
+
+    fn uses_proj(&self) -> bool {
+        !self.spherical
+    }
+
+    fn kind(&self) -> Projection {
+        Projection::EqualEarth
+    }
+
+    fn outline(&self) -> Option<Vec<Range>> {
+        check_built(&self.proj_marker, self.spherical).ok()?;
+        Some(edge_meridians_outline(self))
+    }
+
+    fn natural_extent(&self) -> (Range, Range) {
+        (
+            (self.central_lon - 180.0, self.central_lon + 180.0),
+            (-90.0, 90.0),
+        )
+    }
+}
+
+/// The Mollweide projection, an equal-area pseudocylindrical projection drawing the world as
+/// an ellipse twice as wide as high
+pub struct Mollweide {
+    central_lon: f64,
+    spherical: bool,
+    fallback: bool,
+
+    proj_marker: Option<Proj>,
+}
+
+impl Mollweide {
+    /// Creates a Mollweide projection centered on the prime meridian
+    pub fn new() -> Self {
+        Self {
+            central_lon: 0.0,
+            spherical: false,
+            fallback: FALLBACK_BY_DEFAULT,
+            proj_marker: None,
+        }
+    }
+
+    /// Sets the central meridian of the map
+    pub fn central_lon(mut self, lon: f64) -> Self {
+        self.central_lon = lon;
+        self
+    }
+
+    /// Solves the Mollweide equations on the WGS84 sphere in Rust if [`build`](Self::build) can't
+    /// create the PROJ transformation, instead of panicking, staying within 0.2% of the ellipsoidal
+    /// map. Defaults to off, or on without the `proj` feature.
+    pub fn with_fallback(mut self, fallback: bool) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Creates the underlying PROJ transformation
+    pub fn build(mut self) -> Self {
+        let _central_lon = &self.central_lon.to_string();
+
+        let input = vec![
+            ("proj", "moll"),
+            ("lon_0", _central_lon.as_str()),
+            ("units", "m"),
+        ];
+        let _proj_string = proj_string(input);
+
+        self.proj_marker = build_proj(_proj_string.as_str(), self.fallback);
+        self.spherical = self.proj_marker.is_none();
+
+        self
+    }
+
+    fn spherical_map(&self, v: Range) -> Result<Range, CoordError> {
+        let (lam, phi) = (lon_offset(v.0, self.central_lon), v.1.to_radians());
+        // The auxiliary angle θ of 2θ + sin 2θ = π sin φ, by Newton's method
+        let mut theta = phi;
+        if phi.abs() < FRAC_PI_2 {
+            for _ in 0..30 {
+                let delta = (2.0 * theta + (2.0 * theta).sin() - PI * phi.sin())
+                    / (2.0 + 2.0 * (2.0 * theta).cos());
+                theta -= delta;
+                if delta.abs() < 1e-12 {
+                    break;
+                }
+            }
+        }
+        Ok((
+            WGS84_A * 2.0 * SQRT_2 / PI * lam * theta.cos(),
+            WGS84_A * SQRT_2 * theta.sin(),
+        ))
+    }
+
+    fn spherical_inverse(&self, v: Range) -> Result<Range, CoordError> {
+        let sin_theta = v.1 / (WGS84_A * SQRT_2);
+        if sin_theta.abs() > 1.0 {
+            return Err(CoordError::OutOfDomain(v));
+        }
+        let theta = sin_theta.asin();
+        let lat = ((2.0 * theta + (2.0 * theta).sin()) / PI).asin();
+        let lam = if theta.cos() == 0.0 {
+            0.0
+        } else {
+            PI * v.0 / (2.0 * SQRT_2 * WGS84_A * theta.cos())
+        };
+        if lam.abs() > PI + 1e-9 {
+            return Err(CoordError::OutOfDomain(v));
+        }
+        Ok((
+            adjust_lon(lam.to_degrees() + self.central_lon),
+            lat.to_degrees(),
+        ))
+    }
+}
+
+impl Default for Mollweide {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProjectionS for Mollweide {
+    fn bbox(
+        &self,
+        x_ranged: Option<(f64, f64)>,
+        y_ranged: Option<(f64, f64)>,
+    ) -> Result<(Range, Range), CoordError> {
+        check_built(&self.proj_marker, self.spherical)?;
+        let (lon, lat) = self.natural_extent();
+        sampled_bbox(self, x_ranged.unwrap_or(lon), y_ranged.unwrap_or(lat))
+    }
+
+    fn map(&self, v: Range) -> Range {
+        self.try_map(v).unwrap()
+    }
+
+    fn try_map(&self, v: Range) -> Result<Range, CoordError> {
+        if self.spherical {
+            return self.spherical_map(v);
+        }
+        forward(&self.proj_marker, v)
+    }
+
+    fn map_many(&self, points: &[Range]) -> Vec<Option<Range>> {
+        if self.spherical {
+            return points.iter().map(|p| self.spherical_map(*p).ok()).collect();
+        }
+        forward_many(&self.proj_marker, points)
+    }
+
+    fn inverse(&self, v: Range) -> Result<Range, CoordError> {
+        if self.spherical {
+            return self.spherical_inverse(v);
+        }
+        inverse(&self.proj_marker, v)
+    }
+
+    fn origin(&self) -> Range {
+        (self.central_lon, 0.0)
+    }
+
+    fn uses_proj(&self) -> bool {
+        !self.spherical
+    }
+
+    fn kind(&self) -> Projection {
+        Projection::Mollweide
+    }
+
+    fn outline(&self) -> Option<Vec<Range>> {
+        check_built(&self.proj_marker, self.spherical).ok()?;
+        Some(edge_meridians_outline(self))
+    }
+
+    fn natural_extent(&self) -> (Range, Range) {
+        (
+            (self.central_lon - 180.0, self.central_lon + 180.0),
+            (-90.0, 90.0),
+        )
+    }
+}
+
+/// The Lambert cylindrical equal-area projection and its variants with other standard
+/// parallels, see the presets
+pub struct LambertCylindrical {
+    central_lon: f64,
+    latitude_true_scale: f64,
+    spherical: bool,
+    fallback: bool,
+
+    proj_marker: Option<Proj>,
+}
+
+impl LambertCylindrical {
+    /// Creates a Lambert cylindrical equal-area projection, true to scale along the equator
+    pub fn new() -> Self {
+        Self {
+            central_lon: 0.0,
+            latitude_true_scale: 0.0,
+            spherical: false,
+            fallback: FALLBACK_BY_DEFAULT,
+            proj_marker: None,
+        }
+    }
+
+    /// The Behrmann projection, with standard parallels at 30°
+    pub fn behrmann() -> Self {
+        Self::new().latitude_true_scale(30.0)
+    }
+
+    /// The Gall–Peters projection, with standard parallels at 45°
+    pub fn gall_peters() -> Self {
+        Self::new().latitude_true_scale(45.0)
+    }
+
+    /// The Hobo–Dyer projection, with standard parallels at 37.5°
+    pub fn hobo_dyer() -> Self {
+        Self::new().latitude_true_scale(37.5)
+    }
+
+    /// Sets the central meridian of the map
+    pub fn central_lon(mut self, lon: f64) -> Self {
+        self.central_lon = lon;
+        self
+    }
+
+    /// Sets the standard parallels, where the map is true to scale, to `±lat`
+    pub fn latitude_true_scale(mut self, lat: f64) -> Self {
+        self.latitude_true_scale = lat;
+        self
+    }
+
+    /// Projects the WGS84 sphere onto the cylinder in Rust if [`build`](Self::build) can't create
+    /// the PROJ transformation, instead of panicking, the points moving by up to 0.7% from their
+    /// place on the ellipsoid. Defaults to off, or on without the `proj` feature.
+    pub fn with_fallback(mut self, fallback: bool) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Creates the underlying PROJ transformation
+    pub fn build(mut self) -> Self {
+        let _central_lon = &self.central_lon.to_string();
+        let _latitude_true_scale = &self.latitude_true_scale.to_string();
+
+        let input = vec![
+            ("proj", "cea"),
+            ("lon_0", _central_lon.as_str()),
+            ("lat_ts", _latitude_true_scale.as_str()),
+            ("units", "m"),
+        ];
+        let _proj_string = proj_string(input);
+
+        self.proj_marker = build_proj(_proj_string.as_str(), self.fallback);
+        self.spherical = self.proj_marker.is_none();
+
+        self
+    }
+
+    fn spherical_map(&self, v: Range) -> Result<Range, CoordError> {
+        let k = self.latitude_true_scale.to_radians().cos();
+        Ok((
+            WGS84_A * k * lon_offset(v.0, self.central_lon),
+            WGS84_A * v.1.to_radians().sin() / k,
+        ))
+    }
+
+    fn spherical_inverse(&self, v: Range) -> Result<Range, CoordError> {
+        let k = self.latitude_true_scale.to_radians().cos();
+        let sin_lat = v.1 * k / WGS84_A;
+        if sin_lat.abs() > 1.0 {
+            return Err(CoordError::OutOfDomain(v));
+        }
+        Ok((
+            adjust_lon((v.0 / (WGS84_A * k)).to_degrees() + self.central_lon),
+            sin_lat.asin().to_degrees(),
+        ))
+    }
+}
+
+impl Default for LambertCylindrical {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProjectionS for LambertCylindrical {
+    fn bbox_is_corner_exact(&self) -> bool {
+        true
+    }
+
+    fn map(&self, v: Range) -> Range {
+        self.try_map(v).unwrap()
+    }
+
+    fn try_map(&self, v: Range) -> Result<Range, CoordError> {
+        if self.spherical {
+            return self.spherical_map(v);
+        }
+        forward(&self.proj_marker, v)
+    }
+
+    fn map_many(&self, points: &[Range]) -> Vec<Option<Range>> {
+        if self.spherical {
+            return points.iter().map(|p| self.spherical_map(*p).ok()).collect();
+        }
+        forward_many(&self.proj_marker, points)
+    }
+
+    fn inverse(&self, v: Range) -> Result<Range, CoordError> {
+        if self.spherical {
+            return self.spherical_inverse(v);
+        }
+        inverse(&self.proj_marker, v)
+    }
+
+    fn origin(&self) -> Range {
+        (self.central_lon, 0.0)
+    }
+
+    fn uses_proj(&self) -> bool {
+        !self.spherical
+    }
+
+    fn kind(&self) -> Projection {
+        Projection::LambertCylindrical
+    }
+
+    fn natural_extent(&self) -> (Range, Range) {
+        (
+            (self.central_lon - 180.0, self.central_lon + 180.0),
+            (-90.0, 90.0),
+        )
+    }
+}
+
+/// A rotated pole grid, as used by regional climate models (e.g. CORDEX): the lon/lat grid
+/// of a sphere whose north pole was moved to `(pole_lon, pole_lat)`, drawn with a base
+/// projection of the rotated lon/lat.
+pub struct RotatedPole {
+    pole_lon: f64,
+    pole_lat: f64,
+    base: String,
+    spherical: bool,
+    fallback: bool,
+
+    proj_marker: Option<Proj>,
+}
+
+impl RotatedPole {
+    /// Creates the grid of the rotated north pole at `(pole_lon, pole_lat)`, the
+    /// `grid_north_pole_longitude` and `grid_north_pole_latitude` of the CF conventions.
+    /// The rotated grid is drawn in rotated degrees, like a plate carrée.
+    pub fn new(pole_lon: f64, pole_lat: f64) -> Self {
+        Self {
+            pole_lon,
+            pole_lat,
+            base: "longlat".to_string(),
+            spherical: false,
+            fallback: FALLBACK_BY_DEFAULT,
+            proj_marker: None,
+        }
+    }
+
+    /// Draws the rotated grid with another PROJ projection, e.g. `"merc"`, whose map units
+    /// are meters
+    pub fn base(mut self, proj: &str) -> Self {
+        self.base = proj.to_string();
+        self
+    }
+
+    /// Falls back to the rotation of the sphere computed in Rust if [`build`](Self::build) can't
+    /// create the PROJ transformation, instead of panicking. The rotation is exact, but only the
+    /// default lon/lat base has closed-form equations: `build` still panics for the other bases.
+    /// Defaults to off, or on without the `proj` feature.
+    pub fn with_fallback(mut self, fallback: bool) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Whether the rotated lon/lat are drawn as is, in degrees
+    fn is_geographic(&self) -> bool {
+        matches!(
+            self.base.as_str(),
+            "longlat" | "latlong" | "lonlat" | "latlon"
+        )
+    }
+
+    /// Creates the underlying PROJ transformation
+    pub fn build(mut self) -> Self {
+        let _central_lon = &(self.pole_lon + 180.0).to_string();
+        let _pole_lat = &self.pole_lat.to_string();
+
+        let input = vec![
+            ("proj", "ob_tran"),
+            ("o_proj", self.base.as_str()),
+            ("o_lat_p", _pole_lat.as_str()),
+            ("o_lon_p", "0"),
+            ("lon_0", _central_lon.as_str()),
+        ];
+        let _proj_string = proj_string(input);
+
+        self.proj_marker = build_proj(_proj_string.as_str(), self.fallback && self.is_geographic());
+        self.spherical = self.proj_marker.is_none();
+
+        self
+    }
+
+    /// Rotates the `(lon, lat)` point onto the rotated grid, or back from it if `inverse`,
+    /// with the equations of the oblique transformation of PROJ
+    fn rotate(&self, v: Range, inverse: bool) -> Range {
+        let (sin_p, cos_p) = self.pole_lat.to_radians().sin_cos();
+        let central_lon = self.pole_lon + 180.0;
+        let lam = if inverse {
+            v.0.to_radians()
+        } else {
+            lon_offset(v.0, central_lon)
+        };
+        let (sin_phi, cos_phi) = v.1.to_radians().sin_cos();
+        if inverse {
+            let lat = (sin_p * sin_phi + cos_p * cos_phi * lam.cos()).asin();
+            let lon = (cos_phi * lam.sin()).atan2(sin_p * cos_phi * lam.cos() - cos_p * sin_phi);
+            (adjust_lon(lon.to_degrees() + central_lon), lat.to_degrees())
+        } else {
+            let lat = (sin_p * sin_phi - cos_p * cos_phi * lam.cos()).asin();
+            let lon = (cos_phi * lam.sin()).atan2(sin_p * cos_phi * lam.cos() + cos_p * sin_phi);
+            (lon.to_degrees(), lat.to_degrees())
+        }
+    }
+}
+
+impl ProjectionS for RotatedPole {
+    fn bbox(
+        &self,
+        x_ranged: Option<(f64, f64)>,
+        y_ranged: Option<(f64, f64)>,
+    ) -> Result<(Range, Range), CoordError> {
+        check_built(&self.proj_marker, self.spherical)?;
+        let (lon, lat) = self.natural_extent();
+        sampled_bbox(self, x_ranged.unwrap_or(lon), y_ranged.unwrap_or(lat))
+    }
+
+    fn map(&self, v: Range) -> Range {
+        self.try_map(v).unwrap()
+    }
+
+    fn try_map(&self, v: Range) -> Result<Range, CoordError> {
+        if self.spherical {
+            return Ok(self.rotate(v, false));
+        }
+        let (x, y) = forward(&self.proj_marker, v)?;
+        // PROJ gives the rotated lon/lat in radians
+        if self.is_geographic() {
+            Ok((x.to_degrees(), y.to_degrees()))
+        } else {
+            Ok((x, y))
+        }
+    }
+
+    fn inverse(&self, v: Range) -> Result<Range, CoordError> {
+        if self.spherical {
+            return Ok(self.rotate(v, true));
+        }
+        if self.is_geographic() {
+            inverse(&self.proj_marker, (v.0.to_radians(), v.1.to_radians()))
+        } else {
+            inverse(&self.proj_marker, v)
+        }
+    }
+
+    /// The origin of the rotated grid, on the meridian opposite to the pole
+    fn origin(&self) -> Range {
+        let lon = self.pole_lon + 180.0;
+        (lon - 360.0 * (lon / 360.0).round(), 90.0 - self.pole_lat)
+    }
+
+    fn uses_proj(&self) -> bool {
+        !self.spherical
+    }
+
+    fn kind(&self) -> Projection {
+        Projection::RotatedPole
+    }
+
+    /// The properties of the base projection, which the rotation of the sphere keeps
+    fn properties(&self) -> ProjectionProperties {
+        let none = ProjectionProperties::default();
+        match self.base.as_str() {
+            _ if self.is_geographic() => Projection::PlateCarree.properties(),
+            "eqc" | "aeqd" => ProjectionProperties {
+                equidistant: true,
+                ..none
+            },
+            "merc" | "tmerc" | "lcc" | "stere" | "sterea" => ProjectionProperties {
+                conformal: true,
+                ..none
+            },
+            "cea" | "eqearth" | "moll" | "laea" | "aea" => ProjectionProperties {
+                equal_area: true,
+                ..none
+            },
+            _ => none,
+        }
+    }
+}
+
+/**
+The plate carrée projection of plain lon/lat data (EPSG:4326): longitudes and latitudes, in
+degrees, are drawn as they are.
+
+Beware that a degree of longitude shrinks with the cosine of the latitude on the ground but
+not on this map, so a regional map away from the equator looks stretched east-west, and the
+whole world is twice as wide as tall. [`with_latitude_correction`](Self::with_latitude_correction)
+stretches the latitudes instead, to keep the shapes right around the center of the map.
+*/
+pub struct PlateCarree {
+    central_lon: f64,
+    central_lat: Option<f64>,
+    latitude_correction: bool,
+}
+
+impl PlateCarree {
+    /// Creates the identity mapping of lon/lat in degrees
+    pub fn geographic() -> Self {
+        Self {
+            central_lon: 0.0,
+            central_lat: None,
+            latitude_correction: false,
+        }
+    }
+
+    /// Sets the center of the map: the meridian drawn at `x = 0` and the latitude the
+    /// correction is computed for, instead of the mean latitude of the extent
+    pub fn center(mut self, lon: f64, lat: f64) -> Self {
+        self.central_lon = lon;
+        self.central_lat = Some(lat);
+        self
+    }
+
+    /// Scales latitudes by `1 / cos(central_lat)`, so a degree of latitude keeps its ground
+    /// length relative to a degree of longitude at the center of the map. Without a
+    /// [`center`](Self::center), the correction is computed for the mean latitude of the
+    /// extent the [`LatLonCoord`](super::LatLonCoord) is created with, or the equator for a
+    /// map created from its projected extent.
+    pub fn with_latitude_correction(mut self, correct: bool) -> Self {
+        self.latitude_correction = correct;
+        self
+    }
+
+    /// Does nothing, as the projection doesn't go through PROJ, but keeps the construction
+    /// the same as for the other projections
+    pub fn build(self) -> Self {
+        self
+    }
+
+    /// The projected length of a degree of latitude
+    fn y_scale(&self) -> f64 {
+        self.y_scale_at(self.central_lat.unwrap_or(0.0))
+    }
+
+    /// The projected length of a degree of latitude, corrected for `lat`
+    fn y_scale_at(&self, lat: f64) -> f64 {
+        if self.latitude_correction {
+            1.0 / lat.to_radians().cos().max(1e-6)
+        } else {
+            1.0
+        }
+    }
+}
+
+impl ProjectionS for PlateCarree {
+    fn bbox(
+        &self,
+        x_ranged: Option<(f64, f64)>,
+        y_ranged: Option<(f64, f64)>,
+    ) -> Result<(Range, Range), CoordError> {
+        let (lon, lat) = self.natural_extent();
+        let (lon, lat) = (x_ranged.unwrap_or(lon), y_ranged.unwrap_or(lat));
+        // The latitude `fit_extent` corrects for, if it wasn't called yet
+        let scale = self.y_scale_at(self.central_lat.unwrap_or((lat.0 + lat.1) / 2.0));
+        Ok((
+            (lon.0 - self.central_lon, lon.1 - self.central_lon),
+            (lat.0 * scale, lat.1 * scale),
+        ))
+    }
+
+    fn bbox_is_corner_exact(&self) -> bool {
+        true
+    }
+
+    fn fit_extent(&mut self, _lon: Range, lat: Range) {
+        if self.central_lat.is_none() {
+            self.central_lat = Some((lat.0 + lat.1) / 2.0);
+        }
+    }
+
+    fn map(&self, v: Range) -> Range {
+        (v.0 - self.central_lon, v.1 * self.y_scale())
+    }
+
+    fn inverse(&self, v: Range) -> Result<Range, CoordError> {
+        Ok((v.0 + self.central_lon, v.1 / self.y_scale()))
+    }
+
+    fn origin(&self) -> Range {
+        (self.central_lon, 0.0)
+    }
+
+    fn meters_per_unit(&self) -> f64 {
+        // A degree of the equator
+        WGS84_A.to_radians()
+    }
+
+    fn uses_proj(&self) -> bool {
+        false
+    }
+
+    fn kind(&self) -> Projection {
+        Projection::PlateCarree
+    }
+
+    fn natural_extent(&self) -> (Range, Range) {
+        (
+            (self.central_lon - 180.0, self.central_lon + 180.0),
+            (-90.0, 90.0),
+        )
+    }
+}
+
+/**
+A logarithmic azimuthal projection around a center, for radar and radio range-ring displays.
+As on an azimuthal equidistant map, every point is drawn at its bearing from the center, but
+at the natural logarithm of its distance, so the near field is expanded and each doubling of
+the range adds the same radius.
+
+This isn't a standard projection, and PROJ doesn't know it: it only keeps the bearings from
+the center and the order of the distances, being equidistant in the logarithmic radial sense
+alone. Distances are great circle distances on a sphere of the mean Earth radius. Points
+nearer than the [minimum range](Self::min_range) are drawn at the center, and those beyond
+the maximum range are off the map, whose outline is the circle of the maximum range.
+*/
+pub struct LogAzimuthal {
+    central_lon: f64,
+    central_lat: f64,
+    min_range: f64,
+    max_range: f64,
+}
+
+impl LogAzimuthal {
+    /// Creates the projection centered on `(0, 0)`, showing ranges up to `max_range` meters
+    pub fn new(max_range: f64) -> Self {
+        Self {
+            central_lon: 0.0,
+            central_lat: 0.0,
+            min_range: 1.0,
+            max_range,
+        }
+    }
+
+    /// Sets the point ranges are measured from, e.g. the location of the radar
+    pub fn center(mut self, lon: f64, lat: f64) -> Self {
+        self.central_lon = lon;
+        self.central_lat = lat;
+        self
+    }
+
+    /// Sets the range, in meters, drawn at the center, nearer points being drawn there too.
+    /// Defaults to 1 m, so the projected radius is the logarithm of the range in meters.
+    pub fn min_range(mut self, meters: f64) -> Self {
+        self.min_range = meters;
+        self
+    }
+
+    /// Does nothing, as the projection doesn't go through PROJ, but keeps the construction
+    /// the same as for the other projections
+    pub fn build(self) -> Self {
+        self
+    }
+
+    /// The projected radius of the maximum range
+    fn radius(&self) -> f64 {
+        (self.max_range / self.min_range).ln().max(0.0)
+    }
+}
+
+impl ProjectionS for LogAzimuthal {
+    fn bbox(
+        &self,
+        x_ranged: Option<(f64, f64)>,
+        y_ranged: Option<(f64, f64)>,
+    ) -> Result<(Range, Range), CoordError> {
+        if x_ranged.is_none() && y_ranged.is_none() {
+            let r = self.radius();
+            return Ok(((-r, r), (-r, r)));
+        }
+        let (lon, lat) = self.natural_extent();
+        sampled_bbox(self, x_ranged.unwrap_or(lon), y_ranged.unwrap_or(lat))
+    }
+
+    fn map(&self, v: Range) -> Range {
+        let center = self.origin();
+        let r = (spherical_distance(center, v) / self.min_range)
+            .ln()
+            .max(0.0);
+        let (sin, cos) = spherical_bearing(center, v).to_radians().sin_cos();
+        (r * sin, r * cos)
+    }
+
+    fn outline(&self) -> Option<Vec<Range>> {
+        const SEGMENTS: usize = 180;
+        let r = self.radius();
+        Some(
+            (0..SEGMENTS)
+                .map(|i| {
+                    let a = std::f64::consts::PI * 2.0 * i as f64 / SEGMENTS as f64;
+                    (r * a.cos(), r * a.sin())
+                })
+                .collect(),
+        )
+    }
+
+    fn inverse(&self, v: Range) -> Result<Range, CoordError> {
+        let r = v.0.hypot(v.1);
+        if r == 0.0 {
+            return Ok(self.origin());
+        }
+        let bearing = v.0.atan2(v.1).to_degrees();
+        Ok(spherical_destination(
+            self.origin(),
+            bearing,
+            self.min_range * r.exp(),
+        ))
+    }
+
+    fn is_visible(&self, p: Range) -> bool {
+        spherical_distance(self.origin(), p) <= self.max_range
+    }
+
+    fn uses_proj(&self) -> bool {
+        false
+    }
+
+    fn kind(&self) -> Projection {
+        Projection::LogAzimuthal
+    }
+
+    fn origin(&self) -> Range {
+        (self.central_lon, self.central_lat)
+    }
+
+    fn natural_extent(&self) -> (Range, Range) {
+        (
+            (self.central_lon - 180.0, self.central_lon + 180.0),
+            (-90.0, 90.0),
+        )
+    }
+}
+
+/// A projected coordinate reference system of the EPSG registry, e.g. `32631` for UTM zone
+/// 31N, projecting from WGS84 with the transformation PROJ recommends. Its natural extent is
+/// the [area of use](ProjectionS::area_of_use) of the CRS, so regional systems default to
+/// the region they are valid for.
+///
+/// ```rust
+/// use plotters::coord::geo::{Epsg, ProjectionS};
+///
+/// let utm = Epsg::new(32631).build().unwrap();
+/// let ((west, east), _) = utm.area_of_use().unwrap();
+/// assert_eq!((west, east), (0.0, 6.0));
+/// ```
+pub struct Epsg {
+    code: u32,
+    area: Option<(Range, Range)>,
+
+    proj_marker: Option<Proj>,
+}
+
+impl Epsg {
+    /// Creates the projection of the EPSG `code`
+    pub fn new(code: u32) -> Self {
+        Self {
+            code,
+            area: None,
+            proj_marker: None,
+        }
+    }
+
+    /// Creates the underlying PROJ transformation, failing if the code isn't in the PROJ
+    /// database. There is no fallback without PROJ, which holds the definitions of the codes.
+    pub fn build(mut self) -> Result<Self, CoordError> {
+        let target = format!("EPSG:{}", self.code);
+        // The area of use is the one of the CRS, the transformation may combine several
+        let crs = Proj::new(&target)?;
+        self.area = crs
+            .area_of_use()
+            .ok()
+            .and_then(|(area, _)| area)
+            .map(|area| {
+                // Areas crossing the antimeridian end east of 180°
+                let east = if area.east < area.west {
+                    area.east + 360.0
+                } else {
+                    area.east
+                };
+                ((area.west, east), (area.south, area.north))
+            });
+        self.proj_marker = Some(Proj::new_known_crs("EPSG:4326", &target, None)?);
+        Ok(self)
+    }
+}
+
+impl ProjectionS for Epsg {
+    fn map(&self, v: Range) -> Range {
+        self.try_map(v).unwrap()
+    }
+
+    fn try_map(&self, v: Range) -> Result<Range, CoordError> {
+        let transform = self.proj_marker.as_ref().ok_or(CoordError::Uninital)?;
+        Ok(transform.convert(v)?)
+    }
+
+    fn inverse(&self, v: Range) -> Result<Range, CoordError> {
+        let transform = self.proj_marker.as_ref().ok_or(CoordError::Uninital)?;
+        Ok(transform.project(v, true)?)
+    }
+
+    fn area_of_use(&self) -> Option<(Range, Range)> {
+        self.area
+    }
+
+    fn kind(&self) -> Projection {
+        Projection::Epsg
+    }
+
+    fn natural_extent(&self) -> (Range, Range) {
+        self.area_of_use()
+            .unwrap_or(((-180.0, 180.0), (-90.0, 90.0)))
+    }
+}
+
+/// The outline of a world map bounded by the two meridians opposite to the central one: up
+/// the eastern edge and down the western one, the poles being points or lines
+fn edge_meridians_outline<P: ProjectionS>(proj: &P) -> Vec<Range> {
+    let (lon, _) = proj.natural_extent();
+    let east = densify((lon.1, -90.0), (lon.1, 90.0), DENSIFY_STEP);
+    let west = densify((lon.0, 90.0), (lon.0, -90.0), DENSIFY_STEP);
+    east.chain(Some((lon.1, 90.0)))
+        .chain(west)
+        .chain(Some((lon.0, -90.0)))
+        .filter_map(|p| proj.try_map(p).ok())
+        .collect()
+}
+
+/**
+Picks a sensible projection for the `lon`/`lat` extent, mirroring the suggestions
+of desktop GIS tools:
+
+- Extents whose central latitude is poleward of ±60° use a [`PolarStereographic`]
+  projection of that pole, including caps spanning every longitude.
+- Other near-global extents, spanning at least 180° of longitude or 120° of latitude, use
+  [`EqualEarth`] centered on the extent, or [`Mercator`] if PROJ is too old to provide it.
+- Small regions, spanning at most 20° in both directions, use [`Mercator::web`].
+- Remaining regions straddling the equator use the [`Mercator`] projection, others use a
+  [`LambertConformal`] projection with standard parallels at 1/6 and 5/6 of the
+  latitude span.
+*/
+pub fn auto_projection(lon: Range, lat: Range) -> Box<dyn ProjectionS> {
+    let (lon_span, lat_span) = ((lon.1 - lon.0).abs(), (lat.1 - lat.0).abs());
+    let (center_lon, center_lat) = ((lon.0 + lon.1) / 2.0, (lat.0 + lat.1) / 2.0);
+
+    if center_lat.abs() > 60.0 {
+        let polar = if center_lat > 0.0 {
+            PolarStereographic::north()
+        } else {
+            PolarStereographic::south()
+        };
+        Box::new(polar.central_lon(center_lon).build())
+    } else if lon_span >= 180.0 || lat_span >= 120.0 {
+        match EqualEarth::new().central_lon(center_lon).build() {
+            Ok(proj) => Box::new(proj),
+            Err(_) => Box::new(Mercator::new().build()),
+        }
+    } else if lon_span <= 20.0 && lat_span <= 20.0 {
+        Box::new(Mercator::web().build())
+    } else if lat.0.min(lat.1) < 0.0 && lat.0.max(lat.1) > 0.0 {
+        Box::new(Mercator::new().build())
+    } else {
+        let (south, north) = (lat.0.min(lat.1), lat.0.max(lat.1));
+        Box::new(
+            LambertConformal::new()
+                .center(center_lon, center_lat)
+                .standard_parallels(south + lat_span / 6.0, north - lat_span / 6.0)
+                .build(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord::CoordTranslate;
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_spherical_mercator() {
+        let native = Mercator::spherical().build();
+        // Web Mercator uses the spherical equations on the WGS84 semi-major axis
+        let proj = Mercator::web().build();
+        for lat in [-80.0, -45.0, 0.0, 10.0, 60.0, 84.0].iter() {
+            let p = (37.5, *lat);
+            let (a, b) = (native.map(p), proj.map(p));
+            assert!(
+                (a.0 - b.0).abs() < 1e-6 && (a.1 - b.1).abs() < 1e-6,
+                "{:?}",
+                p
+            );
+
+            let back = native.inverse(a).unwrap();
+            assert!((back.0 - p.0).abs() < 1e-9 && (back.1 - p.1).abs() < 1e-9);
+        }
+        assert!(Mercator::spherical().bbox(None, None).is_ok());
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_mercator_polar_extent() {
+        let merc = Mercator::new().build();
+        assert_eq!(merc.clamp_latitudes((-60.0, 60.0)).unwrap(), (-60.0, 60.0));
+        assert!(matches!(
+            merc.clamp_latitudes((-90.0, 90.0)),
+            Err(CoordError::Clamped(band)) if band == (-80.0, 84.0)
+        ));
+
+        // The poles can't be boxed, the warning tells the band that can
+        assert!(matches!(
+            merc.bbox(None, Some((-90.0, 90.0))),
+            Err(CoordError::Clamped(band)) if band == (-80.0, 84.0)
+        ));
+        let (_, y) = merc.bbox(None, Some((-80.0, 84.0))).unwrap();
+        assert!(y.0.is_finite() && y.1.is_finite());
+
+        // Likewise with the closed-form equations, which don't fail at the poles
+        let spherical = Mercator::spherical().build();
+        assert!(matches!(
+            spherical.bbox(None, Some((-90.0, 90.0))),
+            Err(CoordError::Clamped(_))
+        ));
+
+        // The map falls back to the band, which it shows
+        let coord = super::super::LatLonCoord::new(
+            None,
+            Some((-90.0, 90.0)),
+            (0..400, 400..0),
+            Mercator::new().build(),
+        );
+        assert_eq!(coord.lat, Some((-80.0, 84.0)));
+        assert_eq!(coord.extent().1, (-80.0, 84.0));
+        assert_eq!(coord.visible_bounds().1 .1.round(), 84.0);
+        assert!(matches!(
+            super::super::LatLonCoord::try_new(
+                None,
+                Some((-90.0, 90.0)),
+                (0..400, 400..0),
+                Mercator::new().build(),
+            ),
+            Err(CoordError::Clamped(_))
+        ));
+    }
+
+    #[test]
+    fn test_log_azimuthal() {
+        let radar = LogAzimuthal::new(250_000.0).center(10.0, 50.0).build();
+        let (x, y) = radar.bbox(None, None).unwrap();
+        assert!((x.1 - 250_000f64.ln()).abs() < 1e-12 && x == y);
+        assert_eq!(radar.map((10.0, 50.0)), (0.0, 0.0));
+
+        // Due north, the distance grows with the latitude
+        let north = |dlat: f64| radar.map((10.0, 50.0 + dlat));
+        let radii: Vec<_> = [0.1, 0.2, 0.4, 0.8].iter().map(|d| north(*d).1).collect();
+        for w in radii.windows(2) {
+            assert!((w[1] - w[0] - 2f64.ln()).abs() < 1e-9, "{:?}", radii);
+        }
+        assert!(north(0.1).0.abs() < 1e-9);
+
+        let p = (11.0, 49.5);
+        let back = radar.inverse(radar.map(p)).unwrap();
+        assert!((back.0 - p.0).abs() < 1e-9 && (back.1 - p.1).abs() < 1e-9);
+        assert!(radar.is_visible(p) && !radar.is_visible((20.0, 50.0)));
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_mercator_fallback() {
+        assert!(Mercator::new().with_fallback(true).build().uses_proj());
+        assert!(!Mercator::spherical().build().uses_proj());
+        assert!(!PlateCarree::geographic().uses_proj());
+
+        // What the fallback computes when PROJ fails
+        let (fallback, proj) = (Mercator::spherical().build(), Mercator::new().build());
+        for lat in [0.0, 45.0, 80.0].iter() {
+            let (a, b) = (fallback.map((20.0, *lat)), proj.map((20.0, *lat)));
+            assert!((a.0 - b.0).abs() < 1e-6);
+            assert!((a.1 - b.1).abs() <= 0.007 * b.1.abs());
+        }
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_fallback_on_invalid_parameters() {
+        // PROJ rejects latitudes of origin past the pole, the closed-form equations don't
+        let center = (10.0, 90.000_001);
+        let globe = Orthographic::new()
+            .center(center.0, center.1)
+            .with_fallback(true)
+            .build();
+        assert!(!globe.uses_proj());
+        assert!(globe.bbox(None, None).is_ok());
+
+        let pole = Orthographic::new().center(10.0, 90.0).build();
+        assert!(pole.uses_proj());
+        for p in [(0.0, 60.0), (100.0, 30.0), (-120.0, 5.0)].iter() {
+            let (a, b) = (globe.map(*p), pole.map(*p));
+            assert!(
+                (a.0 - b.0).abs() < 1.0 && (a.1 - b.1).abs() < 1.0,
+                "{:?}",
+                p
+            );
+        }
+
+        let coord = super::super::LatLonCoord::new(None, None, (0..400, 0..400), globe);
+        assert!(!coord.uses_proj());
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    #[should_panic]
+    fn test_invalid_parameters_without_fallback() {
+        Orthographic::new().center(10.0, 90.000_001).build();
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_fallback_equations() {
+        // The closed-form equations against PROJ, the ellipsoid being up to 1% off the sphere
+        fn check<P: ProjectionS>(fallback: P, proj: P, points: &[Range], tolerance: f64) {
+            assert!(!fallback.uses_proj() && proj.uses_proj());
+            for p in points {
+                let (a, b) = (fallback.map(*p), proj.map(*p));
+                assert!(
+                    (a.0 - b.0).abs() < tolerance && (a.1 - b.1).abs() < tolerance,
+                    "{:?}: {:?} {:?}",
+                    p,
+                    a,
+                    b
+                );
+            }
+        }
+        let points = [(-150.0, 60.0), (-20.0, 10.0), (0.0, 45.0), (95.0, 70.0)];
+        let ellipsoid = 0.01 * WGS84_A;
+        check(
+            Orthographic {
+                spherical: true,
+                ..Orthographic::new().center(-30.0, 45.0)
+            },
+            Orthographic::new().center(-30.0, 45.0).build(),
+            &[(-40.0, 50.0), (0.0, 30.0), (-80.0, 60.0)],
+            1e-3,
+        );
+        check(
+            LambertConformal {
+                spherical: true,
+                ..LambertConformal::new()
+            },
+            LambertConformal::new().build(),
+            &points,
+            ellipsoid,
+        );
+        check(
+            PolarStereographic {
+                spherical: true,
+                ..PolarStereographic::north().central_lon(-45.0)
+            },
+            PolarStereographic::north().central_lon(-45.0).build(),
+            &points,
+            ellipsoid,
+        );
+        check(
+            EqualEarth {
+                spherical: true,
+                ..EqualEarth::new()
+            },
+            EqualEarth::new().build().unwrap(),
+            &points,
+            ellipsoid,
+        );
+        check(
+            Mollweide {
+                spherical: true,
+                ..Mollweide::new()
+            },
+            Mollweide::new().build(),
+            &points,
+            ellipsoid,
+        );
+        check(
+            LambertCylindrical {
+                spherical: true,
+                ..LambertCylindrical::behrmann()
+            },
+            LambertCylindrical::behrmann().build(),
+            &points,
+            ellipsoid,
+        );
+        check(
+            RotatedPole {
+                spherical: true,
+                ..RotatedPole::new(-162.0, 39.25)
+            },
+            RotatedPole::new(-162.0, 39.25).build(),
+            &points,
+            1e-6,
+        );
+    }
+
+    #[cfg(not(feature = "proj"))]
+    #[test]
+    fn test_fallback_without_proj() {
+        fn check<P: ProjectionS>(proj: P, expected: &[(Range, Range)]) {
+            assert!(!proj.uses_proj());
+            assert!(proj.bbox(None, None).is_ok());
+            let close = |a: Range, b: Range| (a.0 - b.0).abs() < 1e-6 && (a.1 - b.1).abs() < 1e-6;
+            for (p, xy) in expected {
+                assert!(close(proj.map(*p), *xy), "{:?}: {:?}", p, proj.map(*p));
+            }
+            for p in [(-150.0, 60.0), (-20.0, 10.0), (0.0, 45.0), (95.0, 70.0)].iter() {
+                if let Ok(xy) = proj.try_map(*p) {
+                    let back = proj.inverse(xy).unwrap();
+                    assert!(close(back, *p), "{:?}: {:?}", p, back);
+                }
+            }
+        }
+        let a = WGS84_A;
+
+        check(
+            Mercator::new().with_fallback(true).build(),
+            &[((0.0, 45.0), (0.0, a * 0.881_373_587_019_543))],
+        );
+        check(
+            Orthographic::new().with_fallback(true).build(),
+            &[((90.0, 0.0), (a, 0.0)), ((0.0, 90.0), (0.0, a))],
+        );
+        check(
+            LambertConformal::new()
+                .center(-100.0, 40.0)
+                .with_fallback(true)
+                .build(),
+            &[((-100.0, 40.0), (0.0, 0.0))],
+        );
+        check(
+            PolarStereographic::north().with_fallback(true).build(),
+            &[((0.0, 90.0), (0.0, 0.0)), ((0.0, 0.0), (0.0, -2.0 * a))],
+        );
+        check(
+            PolarStereographic::south().with_fallback(true).build(),
+            &[((90.0, 0.0), (2.0 * a, 0.0))],
+        );
+        let equal_earth = EqualEarth::new().with_fallback(true).build().unwrap();
+        let (x, y) = (
+            equal_earth.map((180.0, 0.0)).0,
+            equal_earth.map((0.0, 90.0)).1,
+        );
+        assert!((x / a - 2.706_63).abs() < 1e-5 && (y / a - 1.317_363).abs() < 1e-6);
+        check(equal_earth, &[]);
+        check(
+            Mollweide::new().with_fallback(true).build(),
+            &[
+                ((180.0, 0.0), (2.0 * SQRT_2 * a, 0.0)),
+                ((0.0, 90.0), (0.0, SQRT_2 * a)),
+            ],
+        );
+        check(
+            LambertCylindrical::new().with_fallback(true).build(),
+            &[((180.0, 90.0), (PI * a, a))],
+        );
+        // The origin of the EURO-CORDEX grid, 1° rotated north being 1° geographic north
+        check(
+            RotatedPole::new(-162.0, 39.25).with_fallback(true).build(),
+            &[((18.0, 50.75), (0.0, 0.0)), ((18.0, 51.75), (0.0, 1.0))],
+        );
+
+        assert!(matches!(
+            EqualEarth::new().with_fallback(false).build(),
+            Err(CoordError::Unavailable { .. })
+        ));
+    }
+
+    #[cfg(not(feature = "proj"))]
+    #[test]
+    fn test_default_fallback_without_proj() {
+        // Without PROJ, the projections use their closed-form equations unless told not to
+        let merc = Mercator::new().build();
+        assert!(!merc.uses_proj());
+        let spherical = Mercator::spherical().build();
+        assert_eq!(merc.map((10.0, 45.0)), spherical.map((10.0, 45.0)));
+
+        let auto = auto_projection((-10.0, 30.0), (35.0, 60.0));
+        assert!(!auto.uses_proj() && auto.try_map((10.0, 45.0)).is_ok());
+    }
+
+    #[cfg(not(feature = "proj"))]
+    #[test]
+    #[should_panic]
+    fn test_no_fallback_without_proj() {
+        Mercator::new().with_fallback(false).build();
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_cylindrical_equal_area_presets() {
+        let aspect = |proj: LambertCylindrical| {
+            let (x, y) = proj.build().bbox(None, None).unwrap();
+            (x.1 - x.0) / (y.1 - y.0)
+        };
+        let lambert = aspect(LambertCylindrical::new());
+        let behrmann = aspect(LambertCylindrical::behrmann());
+        let hobo_dyer = aspect(LambertCylindrical::hobo_dyer());
+        let gall_peters = aspect(LambertCylindrical::gall_peters());
+
+        // Standard parallels further from the equator give narrower maps
+        assert!(lambert > behrmann && behrmann > hobo_dyer && hobo_dyer > gall_peters);
+        assert!((lambert - std::f64::consts::PI).abs() < 0.02);
+        assert!((gall_peters - std::f64::consts::PI / 2.0).abs() < 0.02);
+    }
+
+    #[test]
+    fn test_plate_carree_latitude_correction() {
+        let window = (Some((0.0, 20.0)), Some((40.0, 60.0)));
+        let plain = PlateCarree::geographic().center(10.0, 50.0).build();
+        let corrected = PlateCarree::geographic()
+            .center(10.0, 50.0)
+            .with_latitude_correction(true)
+            .build();
+
+        let (x, y) = plain.bbox(window.0, window.1).unwrap();
+        assert_eq!((x, y), ((-10.0, 10.0), (40.0, 60.0)));
+        let (cx, cy) = corrected.bbox(window.0, window.1).unwrap();
+        assert_eq!(cx, x);
+        let ratio = (cy.1 - cy.0) / (y.1 - y.0);
+        assert!((ratio - 1.0 / 50f64.to_radians().cos()).abs() < 1e-9);
+
+        let (lon, lat) = corrected.inverse(corrected.map((12.0, 47.0))).unwrap();
+        assert!((lon - 12.0).abs() < 1e-9 && (lat - 47.0).abs() < 1e-9);
+
+        // Without a center, the correction is computed for the mean latitude of the extent
+        let uncentered = PlateCarree::geographic().with_latitude_correction(true);
+        let (ux, uy) = uncentered.bbox(window.0, window.1).unwrap();
+        assert_eq!(ux, (0.0, 20.0));
+        assert!((uy.0 - cy.0).abs() < 1e-9 && (uy.1 - cy.1).abs() < 1e-9);
+        let coord =
+            super::super::LatLonCoord::new(window.0, window.1, (0..200, 0..100), uncentered);
+        assert_eq!(coord.translate(&(0.0, 40.0)), (0, 0));
+        assert_eq!(coord.translate(&(20.0, 60.0)), (200, 100));
+        assert_eq!(coord.translate(&(10.0, 50.0)), (100, 50));
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_rotated_pole() {
+        // The EURO-CORDEX grid
+        let grid = RotatedPole::new(-162.0, 39.25).build();
+
+        // The origin of the rotated grid lies on the meridian opposite to the pole
+        let (x, y) = grid.map((18.0, 50.75));
+        assert!(x.abs() < 1e-6 && y.abs() < 1e-6, "{:?}", (x, y));
+
+        // The rotated grid is close to the geographic one around the origin, 1° rotated north
+        // being a bit less than 1° geographic north
+        let (_, y) = grid.map((18.0, 51.75));
+        assert!((y - 1.0).abs() < 1e-6);
+
+        let (lon, lat) = grid.inverse(grid.map((5.0, 45.0))).unwrap();
+        assert!((lon - 5.0).abs() < 1e-6 && (lat - 45.0).abs() < 1e-6);
+
+        let (x, y) = grid.bbox(Some((-10.0, 30.0)), Some((35.0, 70.0))).unwrap();
+        assert!(x.0 < 0.0 && x.1 > 0.0 && y.0 < 0.0 && y.1 > 0.0);
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_orthographic_is_visible() {
+        let globe = Orthographic::new().center(-30.0, 45.0).build();
+        assert!(globe.is_visible((-30.0, 45.0)));
+        assert!(globe.is_visible((0.0, 30.0)));
+        assert!(!globe.is_visible((150.0, -45.0)));
+        assert!(!globe.is_visible((120.0, 0.0)));
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_epsg_area_of_use() {
+        // The Lambert-93 projection of metropolitan France
+        let lambert93 = Epsg::new(2154).build().unwrap();
+        let ((west, east), (south, north)) = lambert93.area_of_use().unwrap();
+        assert!(east - west < 360.0 && east - west > 10.0);
+        assert!(north - south < 180.0);
+        assert!(west < 2.35 && 2.35 < east && south < 48.85 && 48.85 < north);
+        assert_eq!(lambert93.natural_extent(), lambert93.area_of_use().unwrap());
+
+        // The false origin is 700000 m west and 6600000 m south of 3°E 46.5°N
+        let paris = lambert93.map((2.35, 48.85));
+        assert!((paris.0 - 652_000.0).abs() < 5_000.0);
+        assert!((paris.1 - 6_862_000.0).abs() < 5_000.0);
+        let back = lambert93.inverse(paris).unwrap();
+        assert!((back.0 - 2.35).abs() < 1e-6 && (back.1 - 48.85).abs() < 1e-6);
+
+        // The projections built from PROJ strings have no area of use
+        assert!(Mercator::new().build().area_of_use().is_none());
+        assert!(Epsg::new(0).build().is_err());
+    }
+
+    #[test]
+    fn test_auto_projection() {
+        let kind = |lon, lat| auto_projection(lon, lat).kind();
+
+        assert_eq!(kind((-180.0, 180.0), (-90.0, 90.0)), Projection::EqualEarth);
+        // A circumpolar cap is polar, not a thin strip of a world map
+        assert_eq!(
+            kind((-180.0, 180.0), (65.0, 90.0)),
+            Projection::PolarStereographic
+        );
+        assert_eq!(kind((-180.0, 180.0), (-40.0, 70.0)), Projection::EqualEarth);
+        assert_eq!(
+            kind((-60.0, 60.0), (65.0, 90.0)),
+            Projection::PolarStereographic
+        );
+        assert_eq!(
+            kind((0.0, 60.0), (-90.0, -60.0)),
+            Projection::PolarStereographic
+        );
+        assert_eq!(kind((2.0, 8.0), (44.0, 50.0)), Projection::Mercator);
+        assert_eq!(
+            kind((-125.0, -65.0), (25.0, 50.0)),
+            Projection::LambertConformal
+        );
+    }
+}
@@ -0,0 +1,46 @@
+/// Picks a round ground length for a scale bar: the longest 1, 2 or 5 times a power of ten
+/// meters fitting in `target_px` pixels at `meters_per_pixel`, along with its label, in
+/// meters below a kilometer and in kilometers above (e.g. `"500 m"`, `"2 km"`).
+///
+/// A degenerate scale, zero, negative or not finite, gives `(0.0, "0 m")`.
+pub fn nice_scale_length(meters_per_pixel: f64, target_px: f64) -> (f64, String) {
+    let max_length = meters_per_pixel * target_px;
+    if !max_length.is_finite() || max_length <= 0.0 {
+        return (0.0, "0 m".to_string());
+    }
+
+    let exponent = max_length.log10().floor() as i32;
+    let magnitude = 10f64.powi(exponent);
+    let digit = [5.0, 2.0, 1.0]
+        .iter()
+        .copied()
+        .find(|d| d * magnitude <= max_length)
+        .unwrap_or(1.0);
+    let length = digit * magnitude;
+
+    let label = if length >= 1000.0 {
+        format!("{} km", length / 1000.0)
+    } else {
+        format!("{} m", length)
+    };
+    (length, label)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_nice_scale_length() {
+        assert_eq!(nice_scale_length(1.0, 120.0), (100.0, "100 m".to_string()));
+        assert_eq!(nice_scale_length(5.0, 120.0), (500.0, "500 m".to_string()));
+        assert_eq!(nice_scale_length(10.0, 120.0), (1000.0, "1 km".to_string()));
+        assert_eq!(nice_scale_length(30.0, 100.0), (2000.0, "2 km".to_string()));
+        assert_eq!(
+            nice_scale_length(1000.0, 100.0),
+            (100_000.0, "100 km".to_string())
+        );
+        assert_eq!(nice_scale_length(0.01, 60.0), (0.5, "0.5 m".to_string()));
+        assert_eq!(nice_scale_length(0.0, 100.0), (0.0, "0 m".to_string()));
+    }
+}
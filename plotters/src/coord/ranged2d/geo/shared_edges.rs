@@ -0,0 +1,160 @@
+use super::Range;
+use std::collections::HashMap;
+
+/// A vertex compared exactly, as shared edges of a tessellation repeat the same coordinates
+type VertexKey = (u64, u64);
+
+fn key(p: Range) -> VertexKey {
+    (p.0.to_bits(), p.1.to_bits())
+}
+
+/// Merges the `(lon, lat)` polygons of a tessellation into single rings by cancelling the
+/// edges they share, e.g. the regions of a choropleth map that get the same fill, so they
+/// are drawn as one shape without seams between them. The merge is done in geographic
+/// space, before projecting.
+///
+/// This isn't a polygon union: an edge is only cancelled if both polygons have exactly the
+/// same vertices along it, as in administrative boundaries or a grid, so polygons that
+/// overlap, or only touch along part of an edge, are left apart. The rings are closed
+/// implicitly and come out counter-clockwise, with the vertices in the middle of straight
+/// edges dropped; holes left between the polygons come out as clockwise rings.
+pub fn merge_shared_edges(polygons: &[Vec<Range>]) -> Vec<Vec<Range>> {
+    let mut edges: Vec<(Range, Range)> = vec![];
+    for ring in polygons {
+        let mut ring = open_ring(ring);
+        if ring.len() < 3 {
+            continue;
+        }
+        if signed_area(&ring) < 0.0 {
+            ring.reverse();
+        }
+        for i in 0..ring.len() {
+            edges.push((ring[i], ring[(i + 1) % ring.len()]));
+        }
+    }
+
+    // Edges shared by two counter-clockwise rings run in opposite directions
+    let mut count: HashMap<(VertexKey, VertexKey), i32> = HashMap::new();
+    for (a, b) in &edges {
+        *count.entry((key(*a), key(*b))).or_insert(0) += 1;
+    }
+    let mut outgoing: HashMap<VertexKey, Vec<(Range, Range)>> = HashMap::new();
+    for (a, b) in edges {
+        let reverse = count.get_mut(&(key(b), key(a)));
+        match reverse {
+            Some(n) if *n > 0 => *n -= 1,
+            _ => outgoing.entry(key(a)).or_default().push((a, b)),
+        }
+    }
+
+    let mut starts: Vec<VertexKey> = outgoing.keys().copied().collect();
+    starts.sort_unstable();
+    let mut rings = vec![];
+    for start in starts {
+        while let Some((a, mut b)) = outgoing.get_mut(&start).and_then(|e| e.pop()) {
+            let mut ring = vec![a];
+            while key(b) != start {
+                ring.push(b);
+                match outgoing.get_mut(&key(b)).and_then(|e| e.pop()) {
+                    Some((_, next)) => b = next,
+                    None => break,
+                }
+            }
+            let ring = drop_collinear(&ring);
+            if ring.len() > 2 {
+                rings.push(ring);
+            }
+        }
+    }
+    rings
+}
+
+/// The vertices of the ring without the closing one repeating the first
+fn open_ring(ring: &[Range]) -> Vec<Range> {
+    match (ring.first(), ring.last()) {
+        (Some(first), Some(last)) if ring.len() > 1 && key(*first) == key(*last) => {
+            ring[..ring.len() - 1].to_vec()
+        }
+        _ => ring.to_vec(),
+    }
+}
+
+/// Twice the signed area of the ring in the lon/lat plane, positive if counter-clockwise
+fn signed_area(ring: &[Range]) -> f64 {
+    (0..ring.len())
+        .map(|i| {
+            let (a, b) = (ring[i], ring[(i + 1) % ring.len()]);
+            a.0 * b.1 - b.0 * a.1
+        })
+        .sum()
+}
+
+/// The ring without the vertices lying on the straight line between their neighbours
+fn drop_collinear(ring: &[Range]) -> Vec<Range> {
+    let n = ring.len();
+    (0..n)
+        .filter(|&i| {
+            let (a, p, b) = (ring[(i + n - 1) % n], ring[i], ring[(i + 1) % n]);
+            (p.0 - a.0) * (b.1 - a.1) - (p.1 - a.1) * (b.0 - a.0) != 0.0
+        })
+        .map(|i| ring[i])
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn square(lon: f64, lat: f64) -> Vec<Range> {
+        vec![
+            (lon, lat),
+            (lon + 1.0, lat),
+            (lon + 1.0, lat + 1.0),
+            (lon, lat + 1.0),
+        ]
+    }
+
+    /// The vertices of the ring from its lowest one, to compare rings starting anywhere
+    fn from_lowest(ring: &[Range]) -> Vec<Range> {
+        let first = (0..ring.len())
+            .min_by(|&i, &j| ring[i].partial_cmp(&ring[j]).unwrap())
+            .unwrap();
+        ring[first..]
+            .iter()
+            .chain(&ring[..first])
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    fn test_merge_shared_edges() {
+        // The second square is clockwise and closed
+        let mut east = square(1.0, 0.0);
+        east.reverse();
+        east.push(east[0]);
+        let merged = merge_shared_edges(&[square(0.0, 0.0), east]);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(
+            from_lowest(&merged[0]),
+            vec![(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (0.0, 1.0)]
+        );
+
+        // Apart, they stay two rings
+        assert_eq!(
+            merge_shared_edges(&[square(0.0, 0.0), square(5.0, 0.0)]).len(),
+            2
+        );
+
+        // Eight cells around a missing one leave a clockwise hole
+        let cells: Vec<_> = (0..3)
+            .flat_map(|i| (0..3).map(move |j| (i, j)))
+            .filter(|&c| c != (1, 1))
+            .map(|(i, j)| square(i as f64, j as f64))
+            .collect();
+        let mut rings = merge_shared_edges(&cells);
+        rings.sort_by(|a, b| signed_area(b).partial_cmp(&signed_area(a)).unwrap());
+        assert_eq!(rings.len(), 2);
+        assert_eq!(signed_area(&rings[0]), 18.0);
+        assert_eq!(signed_area(&rings[1]), -2.0);
+    }
+}
@@ -0,0 +1,120 @@
+use super::{LatLonCoord, ProjectionS, Range};
+
+/// The tolerance of [`LatLonCoord::simplify_for_view`], in pixels
+const VIEW_TOLERANCE_PX: f64 = 0.5;
+
+/// Simplifies the `(lon, lat)` polyline with the Douglas–Peucker algorithm: the points
+/// within `tolerance_deg` degrees of the simplified line are dropped, measured in the lon/lat
+/// plane. The first and last points are kept.
+///
+/// Run it before [`project_path`](LatLonCoord::project_path) on detailed lines, e.g.
+/// coastlines drawn at a low zoom, so most of their points don't need projecting.
+pub fn simplify(points: &[Range], tolerance_deg: f64) -> Vec<Range> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    let mut keep = vec![false; points.len()];
+    let (first, last) = (0, points.len() - 1);
+    keep[first] = true;
+    keep[last] = true;
+
+    let mut stack = vec![(first, last)];
+    while let Some((start, end)) = stack.pop() {
+        let farthest = (start + 1..end)
+            .map(|i| (i, segment_distance(points[i], points[start], points[end])))
+            .fold(None, |far: Option<(usize, f64)>, (i, d)| match far {
+                Some((_, max)) if max >= d => far,
+                _ => Some((i, d)),
+            });
+        if let Some((i, d)) = farthest {
+            if d > tolerance_deg {
+                keep[i] = true;
+                stack.push((start, i));
+                stack.push((i, end));
+            }
+        }
+    }
+
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(p, keep)| if keep { Some(*p) } else { None })
+        .collect()
+}
+
+/// The distance from `p` to the segment from `a` to `b`
+fn segment_distance(p: Range, a: Range, b: Range) -> f64 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let len2 = dx * dx + dy * dy;
+    let t = if len2 > 0.0 {
+        (((p.0 - a.0) * dx + (p.1 - a.1) * dy) / len2).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    (p.0 - a.0 - t * dx).hypot(p.1 - a.1 - t * dy)
+}
+
+impl<T: ProjectionS> LatLonCoord<T> {
+    /// [Simplifies](simplify) the `(lon, lat)` polyline with a tolerance of about half a pixel
+    /// at the current extent, so the drawing looks the same with fewer points to project
+    pub fn simplify_for_view(&self, points: &[Range]) -> Vec<Range> {
+        let ((w, e), (s, n)) = self.extent();
+        let (x, y) = &self.pixel_range;
+        let width = f64::from((x.end - x.start).abs().max(1));
+        let height = f64::from((y.end - y.start).abs().max(1));
+        let deg_per_px = ((e - w).abs() / width).min((n - s).abs() / height);
+        simplify(points, deg_per_px * VIEW_TOLERANCE_PX)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+
+    /// A dense wavy line along the equator, with a bump in the middle
+    fn dense_line() -> Vec<Range> {
+        (0..=10_000)
+            .map(|i| {
+                let lon = -50.0 + i as f64 * 0.01;
+                let bump = if lon.abs() < 5.0 {
+                    5.0 - lon.abs()
+                } else {
+                    0.0
+                };
+                (lon, bump + 0.001 * (lon * 40.0).sin())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_simplify() {
+        let line = dense_line();
+        let simplified = simplify(&line, 0.01);
+        // The ends and the corners of the bump are all that's left
+        assert_eq!(
+            simplified,
+            vec![line[0], line[4500], line[5000], line[5500], line[10_000]]
+        );
+        assert!(simplified.iter().all(|p| line.contains(p)));
+
+        assert_eq!(simplify(&line, 0.0).len(), line.len());
+        assert_eq!(simplify(&line[..2], 1.0), line[..2].to_vec());
+    }
+
+    #[test]
+    fn test_simplify_for_view() {
+        let line = dense_line();
+        let new_coord = |lon| {
+            LatLonCoord::new(
+                Some(lon),
+                Some((-10.0, 10.0)),
+                (0..500, 0..500),
+                PlateCarree::geographic().build(),
+            )
+        };
+        // At 0.2° per pixel, the wiggles vanish, but not a few pixels wide
+        assert_eq!(new_coord((-50.0, 50.0)).simplify_for_view(&line).len(), 5);
+        assert!(new_coord((-0.1, 0.1)).simplify_for_view(&line).len() > 100);
+    }
+}
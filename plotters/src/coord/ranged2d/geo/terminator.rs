@@ -0,0 +1,162 @@
+use super::{LatLonCoord, ProjectionS, Range};
+use chrono::{DateTime, Datelike, Timelike, Utc};
+use std::f64::consts::PI;
+
+/// The smallest solar declination used, in radians, so the terminator stays a function of
+/// the longitude at the equinoxes
+const MIN_DECLINATION: f64 = 1e-6;
+
+/// Returns the `(lon, lat)` subsolar point at `time`, where the sun is at the zenith, from the
+/// NOAA approximations of the solar declination and the equation of time (within a few
+/// tenths of a degree)
+pub fn subsolar_point(time: &DateTime<Utc>) -> Range {
+    let hours = f64::from(time.hour())
+        + f64::from(time.minute()) / 60.0
+        + f64::from(time.second()) / 3600.0;
+    // The fractional year, in radians
+    let g = 2.0 * PI / 365.0 * (f64::from(time.ordinal0()) + (hours - 12.0) / 24.0);
+
+    let declination = 0.006918 - 0.399912 * g.cos() + 0.070257 * g.sin()
+        - 0.006758 * (2.0 * g).cos()
+        + 0.000907 * (2.0 * g).sin()
+        - 0.002697 * (3.0 * g).cos()
+        + 0.00148 * (3.0 * g).sin();
+    // In minutes
+    let equation_of_time = 229.18
+        * (0.000075 + 0.001868 * g.cos()
+            - 0.032077 * g.sin()
+            - 0.014615 * (2.0 * g).cos()
+            - 0.040849 * (2.0 * g).sin());
+
+    let lon = -15.0 * (hours - 12.0 + equation_of_time / 60.0);
+    (
+        lon - 360.0 * (lon / 360.0).round(),
+        declination.to_degrees(),
+    )
+}
+
+/// Returns `n` `(lon, lat)` points evenly spaced along the terminator at `time`, the great
+/// circle 90° away from the [subsolar point](subsolar_point) separating day from night. The
+/// circle is independent of any map: see [`LatLonCoord::terminator_line`] for the line
+/// across the extent of a map.
+pub fn terminator(time: &DateTime<Utc>, n: usize) -> Vec<Range> {
+    let (sun_lon, sun_lat) = subsolar_point(time);
+    let (sin_lat, cos_lat) = sun_lat.to_radians().sin_cos();
+    (0..n)
+        .map(|i| {
+            let bearing = 2.0 * PI * i as f64 / n as f64;
+            let lat = (cos_lat * bearing.cos()).asin();
+            let lon = sun_lon
+                + (bearing.sin() * cos_lat)
+                    .atan2(-sin_lat * lat.sin())
+                    .to_degrees();
+            (lon - 360.0 * (lon / 360.0).round(), lat.to_degrees())
+        })
+        .collect()
+}
+
+impl<T: ProjectionS> LatLonCoord<T> {
+    /// Returns the day/night boundary at `time` across the longitude extent of the map, as
+    /// `(lon, lat)` points west to east. Its latitudes are clamped to the latitude extent, so
+    /// around the solstices, when a pole has polar day or night, the line runs along the edge
+    /// of the map where the terminator is beyond it.
+    pub fn terminator_line(&self, time: &DateTime<Utc>) -> Vec<Range> {
+        let (sun_lon, sun_lat) = subsolar_point(time);
+        let declination = match sun_lat.to_radians() {
+            d if d.abs() < MIN_DECLINATION => MIN_DECLINATION.copysign(d),
+            d => d,
+        };
+        let ((w, e), (s, n)) = self.extent();
+        let (south, north) = (s.min(n), s.max(n));
+        let count = ((e - w).abs() / self.densify_step().min(1.0))
+            .ceil()
+            .max(1.0) as usize;
+        (0..=count)
+            .map(|i| {
+                let lon = w + (e - w) * i as f64 / count as f64;
+                let hour_angle = (lon - sun_lon).to_radians();
+                let lat = (-hour_angle.cos() / declination.tan()).atan().to_degrees();
+                (lon, lat.max(south).min(north))
+            })
+            .collect()
+    }
+
+    /// Returns the night side of the map at `time` as a `(lon, lat)` ring: the
+    /// [terminator](Self::terminator_line) closed along the edge of the map on the side of the
+    /// pole in polar night.
+    pub fn night_polygon(&self, time: &DateTime<Utc>) -> Vec<Range> {
+        let (_, sun_lat) = subsolar_point(time);
+        let (_, (s, n)) = self.extent();
+        // The pole facing away from the sun, the south one at the equinoxes
+        let pole = if sun_lat > 0.0 { s.min(n) } else { s.max(n) };
+        let mut ring = self.terminator_line(time);
+        if let (Some(&(w, _)), Some(&(e, _))) = (ring.first(), ring.last()) {
+            ring.push((e, pole));
+            ring.push((w, pole));
+        }
+        ring
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::*;
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_subsolar_point() {
+        let june_solstice = Utc.with_ymd_and_hms(2024, 6, 20, 12, 0, 0).unwrap();
+        let (lon, lat) = subsolar_point(&june_solstice);
+        assert!(
+            (lat - 23.44).abs() < 0.2 && lon.abs() < 1.0,
+            "{:?}",
+            (lon, lat)
+        );
+
+        let evening = Utc.with_ymd_and_hms(2024, 6, 20, 18, 0, 0).unwrap();
+        assert!((subsolar_point(&evening).0 + 90.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_terminator() {
+        let time = Utc.with_ymd_and_hms(2024, 5, 2, 7, 30, 0).unwrap();
+        let (sun_lon, sun_lat) = subsolar_point(&time);
+        let circle = terminator(&time, 90);
+        assert_eq!(circle.len(), 90);
+
+        // Every point is a quarter turn away from the subsolar point
+        let (sun_lon, sun_lat) = (sun_lon.to_radians(), sun_lat.to_radians());
+        for (lon, lat) in circle {
+            let (lon, lat) = (lon.to_radians(), lat.to_radians());
+            let cos_distance =
+                sun_lat.sin() * lat.sin() + sun_lat.cos() * lat.cos() * (lon - sun_lon).cos();
+            assert!(cos_distance.abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_equinox_terminator() {
+        let world = LatLonCoord::new(
+            None,
+            None,
+            (0..360, 0..180),
+            PlateCarree::geographic().build(),
+        );
+        let noon = Utc.with_ymd_and_hms(2024, 3, 20, 12, 0, 0).unwrap();
+        let line = world.terminator_line(&noon);
+
+        // The terminator runs from pole to pole along the 90th meridians
+        assert!(line.iter().any(|p| p.1 > 85.0) && line.iter().any(|p| p.1 < -85.0));
+        assert!(line
+            .iter()
+            .filter(|p| p.1.abs() < 45.0)
+            .all(|p| (p.0.abs() - 90.0).abs() < 5.0));
+
+        // At the solstice, the north pole is in daylight and the night closes to the south
+        let solstice = Utc.with_ymd_and_hms(2024, 6, 20, 12, 0, 0).unwrap();
+        let night = world.night_polygon(&solstice);
+        assert_eq!(night.last(), Some(&(-180.0, -90.0)));
+        assert!(night.iter().all(|p| p.1 < 67.0));
+    }
+}
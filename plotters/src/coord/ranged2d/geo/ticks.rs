@@ -0,0 +1,144 @@
+use super::{LatLonCoord, ProjectionS, Range};
+use plotters_backend::BackendCoord;
+
+/// An edge of the frame of a map, as seen on screen
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrameEdge {
+    /// The top edge, at the smallest pixel y
+    Top,
+    /// The bottom edge, at the largest pixel y
+    Bottom,
+    /// The left edge, at the smallest pixel x
+    Left,
+    /// The right edge, at the largest pixel x
+    Right,
+}
+
+/// Distance, in pixels, under which a crossing is considered to be at a corner of the frame
+const CORNER_TOLERANCE: f64 = 0.5;
+
+impl<T: ProjectionS> LatLonCoord<T> {
+    /// Returns where the `lon` meridian crosses the frame of the map, in backend pixels.
+    /// A meridian through a corner is reported on the top or bottom edge only.
+    pub fn meridian_frame_crossings(&self, lon: f64) -> Vec<(FrameEdge, BackendCoord)> {
+        self.frame_crossings(&self.meridian_points(lon), true)
+    }
+
+    /// Returns where the `lat` parallel crosses the frame of the map, in backend pixels.
+    /// A parallel through a corner is reported on the left or right edge only.
+    pub fn parallel_frame_crossings(&self, lat: f64) -> Vec<(FrameEdge, BackendCoord)> {
+        self.frame_crossings(&self.parallel_points(lat), false)
+    }
+
+    /// Intersects the projected `(lon, lat)` polyline with the edges of the frame, keeping
+    /// corners for the horizontal edges if `horizontal` and for the vertical ones otherwise
+    fn frame_crossings(&self, line: &[Range], horizontal: bool) -> Vec<(FrameEdge, BackendCoord)> {
+        let (x_pixels, y_pixels) = &self.pixel_range;
+        let (left, right) = sorted((f64::from(x_pixels.start), f64::from(x_pixels.end)));
+        let (top, bottom) = sorted((f64::from(y_pixels.start), f64::from(y_pixels.end)));
+
+        let points: Vec<_> = line
+            .iter()
+            .filter_map(|p| self.project_f64(*p))
+            .map(|p| self.to_pixel_f64(p))
+            .collect();
+
+        let edges = [
+            (FrameEdge::Top, true, top, (left, right)),
+            (FrameEdge::Bottom, true, bottom, (left, right)),
+            (FrameEdge::Left, false, left, (top, bottom)),
+            (FrameEdge::Right, false, right, (top, bottom)),
+        ];
+
+        let mut crossings = vec![];
+        for (edge, is_horizontal, at, span) in edges.iter().copied() {
+            // The coordinate across the edge and the one along it
+            let split = |p: Range| {
+                if is_horizontal {
+                    (p.1, p.0)
+                } else {
+                    (p.0, p.1)
+                }
+            };
+            for (i, w) in points.windows(2).enumerate() {
+                let ((a, a_along), (b, b_along)) = (split(w[0]), split(w[1]));
+                if (a - at) * (b - at) > 0.0 || a == b {
+                    continue;
+                }
+                // A crossing at a vertex belongs to the segment starting there
+                if b == at && i + 2 < points.len() {
+                    continue;
+                }
+                let along = a_along + (b_along - a_along) * (at - a) / (b - a);
+                if along < span.0 - CORNER_TOLERANCE || along > span.1 + CORNER_TOLERANCE {
+                    continue;
+                }
+                let at_corner =
+                    along - span.0 < CORNER_TOLERANCE || span.1 - along < CORNER_TOLERANCE;
+                if at_corner && is_horizontal != horizontal {
+                    continue;
+                }
+                let pixel = if is_horizontal {
+                    (along, at)
+                } else {
+                    (at, along)
+                };
+                crossings.push((edge, (pixel.0.round() as i32, pixel.1.round() as i32)));
+            }
+        }
+        crossings
+    }
+
+    /// Maps the projected point to backend pixels, without rounding
+    fn to_pixel_f64(&self, (x, y): Range) -> Range {
+        let (x_pixels, y_pixels) = &self.pixel_range;
+        let scale = |v: f64, (v0, v1): Range, pixels: &std::ops::Range<i32>| {
+            let (p0, p1) = (f64::from(pixels.start), f64::from(pixels.end));
+            p0 + (v - v0) / (v1 - v0) * (p1 - p0)
+        };
+        (scale(x, self.x, x_pixels), scale(y, self.y, y_pixels))
+    }
+}
+
+fn sorted((a, b): Range) -> Range {
+    (a.min(b), a.max(b))
+}
+
+#[cfg(all(test, feature = "proj"))]
+mod test {
+    use super::super::*;
+    use super::*;
+
+    #[test]
+    fn test_frame_crossings() {
+        // North up, as on a chart
+        let height = 0..399;
+        let coord = LatLonCoord::new(
+            Some((-30.0, 30.0)),
+            Some((20.0, 60.0)),
+            (0..599, height.end..height.start),
+            Mercator::new().build(),
+        );
+        for lon in [-30.0, -20.0, 0.0, 10.0, 30.0].iter() {
+            let crossings = coord.meridian_frame_crossings(*lon);
+            let bottom: Vec<_> = crossings
+                .iter()
+                .filter(|(edge, _)| *edge == FrameEdge::Bottom)
+                .collect();
+            assert_eq!(bottom.len(), 1, "{:?}", crossings);
+            let expected = coord.translate(&(*lon, 20.0));
+            assert!((bottom[0].1 .0 - expected.0).abs() <= 1);
+            assert_eq!(bottom[0].1 .1, 399);
+            // The corner meridians aren't reported on the side edges
+            assert!(crossings
+                .iter()
+                .all(|(edge, _)| *edge == FrameEdge::Bottom || *edge == FrameEdge::Top));
+        }
+
+        let parallel = coord.parallel_frame_crossings(40.0);
+        assert_eq!(parallel.len(), 2);
+        assert_eq!(parallel[0].0, FrameEdge::Left);
+        assert_eq!(parallel[1].0, FrameEdge::Right);
+        assert!((parallel[0].1 .1 - coord.translate(&(0.0, 40.0)).1).abs() <= 1);
+    }
+}
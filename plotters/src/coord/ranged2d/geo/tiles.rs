@@ -0,0 +1,76 @@
+use super::Range;
+use std::f64::consts::PI;
+
+/// The latitude limit of web map tiles, where the Web Mercator square ends
+const MAX_TILE_LATITUDE: f64 = 85.051_128_779_806_59;
+
+/// The deepest zoom level, the last one whose tile indices fit in a `u32`
+const MAX_ZOOM: u32 = 31;
+
+/// The number of tiles across the world at zoom `z`, deeper zoom levels being clamped to
+/// [`MAX_ZOOM`]
+fn tile_count(z: u32) -> f64 {
+    f64::from(1u32 << z.min(MAX_ZOOM))
+}
+
+/// Returns the `(lon, lat)` north-west corner of the slippy map tile `x`/`y` at zoom `z`, as
+/// served by OpenStreetMap and most web map tile servers. Tile `z`/`2^z`/`2^z` gives the
+/// south-east corner of the world. Zoom levels beyond 31 are clamped to it.
+pub fn tile_to_lonlat(z: u32, x: u32, y: u32) -> Range {
+    let n = tile_count(z);
+    let lon = f64::from(x) / n * 360.0 - 180.0;
+    let lat = (PI * (1.0 - 2.0 * f64::from(y) / n)).sinh().atan();
+    (lon, lat.to_degrees())
+}
+
+/// Returns the `(x, y)` slippy map tile at zoom `z` containing the `(lon, lat)` point.
+/// Latitudes beyond the ±85.0511° limit of web map tiles are clamped to it, and longitudes
+/// wrap around. Zoom levels beyond 31 are clamped to it.
+pub fn lonlat_to_tile(lon: f64, lat: f64, z: u32) -> (u32, u32) {
+    let n = tile_count(z);
+    let lon = lon - 360.0 * ((lon + 180.0) / 360.0).floor();
+    let lat = lat
+        .clamp(-MAX_TILE_LATITUDE, MAX_TILE_LATITUDE)
+        .to_radians();
+    let x = (lon + 180.0) / 360.0 * n;
+    let y = (1.0 - lat.tan().asinh() / PI) / 2.0 * n;
+    let index = |v: f64| v.floor().clamp(0.0, n - 1.0) as u32;
+    (index(x), index(y))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tile_to_lonlat() {
+        let (lon, lat) = tile_to_lonlat(0, 0, 0);
+        assert!((lon + 180.0).abs() < 1e-9 && (lat - MAX_TILE_LATITUDE).abs() < 1e-9);
+
+        // The OpenStreetMap tile 10/550/335 over Berlin
+        let (lon, lat) = tile_to_lonlat(10, 550, 335);
+        assert!((lon - 13.359375).abs() < 1e-9 && (lat - 52.6964).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_lonlat_to_tile() {
+        assert_eq!(lonlat_to_tile(13.405, 52.52, 10), (550, 335));
+        assert_eq!(lonlat_to_tile(-0.1276, 51.5072, 12), (2046, 1362));
+        assert_eq!(lonlat_to_tile(0.0, 0.0, 0), (0, 0));
+
+        // Beyond the tiled square, the latitudes clamp to the edge tiles
+        assert_eq!(lonlat_to_tile(0.0, 89.9, 3), (4, 0));
+        assert_eq!(lonlat_to_tile(0.0, -90.0, 3), (4, 7));
+        assert_eq!(lonlat_to_tile(180.0, 0.0, 3), (0, 4));
+
+        // Zoom levels whose tiles overflow a u32 clamp to the deepest one
+        let deepest = lonlat_to_tile(179.999, -85.0, 31);
+        assert_eq!(lonlat_to_tile(179.999, -85.0, 40), deepest);
+        assert_eq!(tile_to_lonlat(40, 1, 1), tile_to_lonlat(31, 1, 1));
+
+        let (x, y) = lonlat_to_tile(2.35, 48.85, 14);
+        let (w, n) = tile_to_lonlat(14, x, y);
+        let (e, s) = tile_to_lonlat(14, x + 1, y + 1);
+        assert!(w <= 2.35 && 2.35 < e && s < 48.85 && 48.85 <= n);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::coord::cartesian::{Cartesian2d, MeshLine};
+use crate::coord::geo::CoordError;
 use crate::coord::ranged1d::{KeyPointHint, Ranged};
 use crate::coord::{CoordTranslate, Shift};
 use crate::element::{CoordMapper, Drawable, PointCollection};
@@ -25,6 +26,16 @@ pub struct Rect {
 }
 
 impl Rect {
+    /// Creates the rectangle from its upper-left and bottom-right corners, in backend pixels
+    pub fn new((x0, y0): BackendCoord, (x1, y1): BackendCoord) -> Self {
+        Self { x0, y0, x1, y1 }
+    }
+
+    /// The upper-left and bottom-right corners of the rectangle
+    pub fn corners(&self) -> (BackendCoord, BackendCoord) {
+        ((self.x0, self.y0), (self.x1, self.y1))
+    }
+
     /// Split the rectangle into a few smaller rectangles
     fn split<'a, BPI: IntoIterator<Item = &'a i32> + 'a>(
         &'a self,
@@ -143,6 +154,8 @@ pub enum DrawingAreaErrorKind<E: Error + Send + Sync> {
     SharingError,
     /// The error caused by invalid layout
     LayoutError,
+    /// The error is due to a map projection failure
+    CoordError(CoordError),
 }
 
 impl<E: Error + Send + Sync> std::fmt::Display for DrawingAreaErrorKind<E> {
@@ -153,11 +166,25 @@ impl<E: Error + Send + Sync> std::fmt::Display for DrawingAreaErrorKind<E> {
                 write!(fmt, "Multiple backend operation in progress")
             }
             DrawingAreaErrorKind::LayoutError => write!(fmt, "Bad layout"),
+            DrawingAreaErrorKind::CoordError(e) => write!(fmt, "coordinate error: {}", e),
+        }
+    }
+}
+
+impl<E: Error + Send + Sync> Error for DrawingAreaErrorKind<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DrawingAreaErrorKind::CoordError(e) => Some(e),
+            _ => None,
         }
     }
 }
 
-impl<E: Error + Send + Sync> Error for DrawingAreaErrorKind<E> {}
+impl<E: Error + Send + Sync> From<CoordError> for DrawingAreaErrorKind<E> {
+    fn from(error: CoordError) -> Self {
+        DrawingAreaErrorKind::CoordError(error)
+    }
+}
 
 #[allow(type_alias_bounds)]
 type DrawingAreaError<T: DrawingBackend> = DrawingAreaErrorKind<T::ErrorType>;
@@ -856,4 +883,23 @@ mod drawing_area_tests {
 
         drawing_area.fill(&RED).unwrap();
     }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn test_coord_error_source() {
+        use crate::coord::geo::{CoordError, Mercator, ProjectionS};
+        use std::error::Error;
+
+        let error = Mercator::new().build().try_map((0.0, 90.0)).unwrap_err();
+        let error: DrawingAreaErrorKind<std::io::Error> = error.into();
+        // The cause is only reported as the source, not repeated in the message
+        assert_eq!(
+            error.to_string(),
+            "coordinate error: failed to project the point"
+        );
+
+        let source = error.source().unwrap();
+        assert!(source.downcast_ref::<CoordError>().is_some());
+        assert!(source.source().unwrap().is::<proj::ProjError>());
+    }
 }
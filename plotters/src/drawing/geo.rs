@@ -0,0 +1,96 @@
+use super::DrawingArea;
+use crate::coord::geo::{LatLonCoord, ProjectionS};
+use crate::coord::Shift;
+use plotters_backend::DrawingBackend;
+use std::ops::Range;
+
+impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
+    /// Applies a map of the `lon`/`lat` extent, drawn with the `proj` projection, to the whole
+    /// drawing area, north up. `None` ranges fall back to the projection's natural extent.
+    ///
+    /// Unlike [`ChartBuilder::build_geo_coord`](crate::chart::ChartBuilder::build_geo_coord),
+    /// this doesn't reserve any room for labels, which suits small multiples of maps.
+    pub fn apply_geo_coord<T: ProjectionS>(
+        &self,
+        lon: Option<(f64, f64)>,
+        lat: Option<(f64, f64)>,
+        proj: T,
+    ) -> DrawingArea<DB, LatLonCoord<T>> {
+        self.apply_coord_spec(LatLonCoord::new(lon, lat, self.geo_pixel_range(), proj))
+    }
+
+    /// The pixel range of the area for a map, north up
+    pub(crate) fn geo_pixel_range(&self) -> (Range<i32>, Range<i32>) {
+        let mut pixel_range = self.get_pixel_range();
+        pixel_range.0.end -= 1;
+        pixel_range.1.end -= 1;
+        pixel_range.1 = pixel_range.1.end..pixel_range.1.start;
+        pixel_range
+    }
+}
+
+/**
+Splits the drawing area into `n` side-by-side areas of the same size, to compare several
+projections of the same data. Each area gets its own map with
+[`apply_geo_coord`](DrawingArea::apply_geo_coord), drawing on one of them leaves the others
+untouched. A zero `n` gives back the whole area.
+
+```rust,no_run
+use plotters::coord::geo::{Mercator, Mollweide};
+use plotters::drawing::split_geo;
+use plotters::prelude::*;
+
+let root = BitMapBackend::new("projections.png", (1024, 384)).into_drawing_area();
+let areas = split_geo(root, 2);
+
+let mercator = areas[0].apply_geo_coord(None, None, Mercator::new().build());
+let mollweide = areas[1].apply_geo_coord(None, None, Mollweide::new().build());
+let paris = Circle::new((2.35, 48.85), 3, RED.filled());
+mercator.draw(&paris).unwrap();
+mollweide.draw(&paris).unwrap();
+```
+*/
+pub fn split_geo<DB: DrawingBackend>(
+    area: DrawingArea<DB, Shift>,
+    n: usize,
+) -> Vec<DrawingArea<DB, Shift>> {
+    area.split_evenly((1, n.max(1)))
+}
+
+#[cfg(all(test, feature = "proj"))]
+mod test {
+    use super::*;
+    use crate::coord::geo::{Mercator, Mollweide};
+    use crate::prelude::*;
+
+    #[test]
+    fn test_split_geo() {
+        let drawing_area = create_mocked_drawing_area(400, 200, |m| {
+            m.check_draw_circle(|_, _, _, center, _| assert!(center.0 < 200));
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 1));
+        });
+
+        let areas = split_geo(drawing_area, 2);
+        assert_eq!(areas.len(), 2);
+
+        let mercator = areas[0].apply_geo_coord(
+            Some((-10.0, 10.0)),
+            Some((-10.0, 10.0)),
+            Mercator::new().build(),
+        );
+        let mollweide = areas[1].apply_geo_coord(None, None, Mollweide::new().build());
+
+        let center =
+            |p: (i32, i32), (x, y): (i32, i32)| (p.0 - x).abs() <= 1 && (p.1 - y).abs() <= 1;
+        assert!(center(
+            mercator.as_coord_spec().translate(&(0.0, 0.0)),
+            (100, 100)
+        ));
+        assert!(center(
+            mollweide.as_coord_spec().translate(&(0.0, 0.0)),
+            (300, 100)
+        ));
+
+        mercator.draw(&Circle::new((0.0, 0.0), 3, RED)).unwrap();
+    }
+}
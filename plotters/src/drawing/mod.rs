@@ -12,7 +12,10 @@ about the [coordinate abstraction](../coord/index.html) and [element system](../
 */
 mod area;
 mod backend_impl;
+mod geo;
 
 pub use area::{DrawingArea, DrawingAreaErrorKind, IntoDrawingArea, Rect};
 
 pub use backend_impl::*;
+
+pub use geo::split_geo;
@@ -2,7 +2,8 @@ use std::borrow::Borrow;
 use std::i32;
 
 use super::{Drawable, PointCollection};
-use crate::style::{FontDesc, FontResult, LayoutBox, TextStyle};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{FontDesc, FontResult, FontTransform, LayoutBox, TextStyle};
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
 /// A single line text element. This can be owned or borrowed string, dependents on
@@ -240,3 +241,150 @@ impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB>
         Ok(())
     }
 }
+
+/// How far, in degrees, the path under a `CurvedText` may stray from a quarter turn
+const CURVED_TEXT_TOLERANCE: f64 = 20.0;
+
+/**
+A single line text element set along a polyline, e.g. a degree label following a projected
+meridian from [`LatLonCoord::meridian_line`](crate::coord::geo::LatLonCoord::meridian_line).
+
+The characters are centered on the middle of the path, each one turned to follow the direction
+of the path under it. As backends only turn text by quarter turns, a character takes the
+quarter turn closest to the path, and text that would read upside down runs the other way.
+If the path strays too far from a quarter turn under the text, or is too short for it, the
+text is drawn horizontally at the middle of the path instead.
+*/
+pub struct CurvedText<'a, Coord, T: Borrow<str>> {
+    text: T,
+    path: Vec<Coord>,
+    style: TextStyle<'a>,
+}
+
+impl<'a, Coord, T: Borrow<str>> CurvedText<'a, Coord, T> {
+    /// Create a new curved text element
+    /// - `text`: The text for the element
+    /// - `path`: The polyline the text follows
+    /// - `style`: The text style, its anchor and transform are replaced
+    pub fn new<P: Into<Vec<Coord>>, S: Into<TextStyle<'a>>>(text: T, path: P, style: S) -> Self {
+        Self {
+            text,
+            path: path.into(),
+            style: style.into(),
+        }
+    }
+}
+
+impl<'b, 'a, Coord: 'a, T: Borrow<str> + 'a> PointCollection<'a, Coord>
+    for &'a CurvedText<'b, Coord, T>
+{
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.path
+    }
+}
+
+/// The point at the arc length `s` along the polyline and the direction of the path there,
+/// in degrees clockwise from the x axis
+fn point_along(path: &[(f64, f64)], mut s: f64) -> ((f64, f64), f64) {
+    let segments = path.len().saturating_sub(1);
+    for i in 0..segments {
+        let (a, b) = (path[i], path[i + 1]);
+        let len = (b.0 - a.0).hypot(b.1 - a.1);
+        if s <= len || i + 1 == segments {
+            let t = if len > 0.0 { (s / len).min(1.0) } else { 0.0 };
+            let p = (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t);
+            return (p, (b.1 - a.1).atan2(b.0 - a.0).to_degrees());
+        }
+        s -= len;
+    }
+    (path[0], 0.0)
+}
+
+/// The quarter turn closest to the direction `angle`, and how far from it `angle` is
+fn quarter_turn(angle: f64) -> (FontTransform, f64) {
+    let quarters = (angle / 90.0).round();
+    let transform = match (quarters as i64).rem_euclid(4) {
+        0 => FontTransform::None,
+        1 => FontTransform::Rotate90,
+        2 => FontTransform::Rotate180,
+        _ => FontTransform::Rotate270,
+    };
+    (transform, (angle - quarters * 90.0).abs())
+}
+
+impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB> for CurvedText<'a, Coord, T> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let mut path: Vec<_> = points.map(|(x, y)| (f64::from(x), f64::from(y))).collect();
+        if path.is_empty() {
+            return Ok(());
+        }
+        let centered = self.style.pos(Pos::new(HPos::Center, VPos::Center));
+        let length: f64 = path
+            .windows(2)
+            .map(|w| (w[1].0 - w[0].0).hypot(w[1].1 - w[0].1))
+            .sum();
+
+        let text = self.text.borrow();
+        let mut widths = vec![];
+        for c in text.chars() {
+            let (w, _) = backend.estimate_text_size(c.encode_utf8(&mut [0; 4]), &centered)?;
+            widths.push(f64::from(w));
+        }
+        let width: f64 = widths.iter().sum();
+
+        // Read the text the other way rather than upside down
+        if let (FontTransform::Rotate180, _) = quarter_turn(point_along(&path, length / 2.0).1) {
+            path.reverse();
+        }
+
+        let mut glyphs = vec![];
+        let mut s = (length - width) / 2.0;
+        for (c, w) in text.chars().zip(widths).filter(|_| width <= length) {
+            let (p, angle) = point_along(&path, s + w / 2.0);
+            let (transform, error) = quarter_turn(angle);
+            if error > CURVED_TEXT_TOLERANCE {
+                glyphs.clear();
+                break;
+            }
+            glyphs.push((c, (p.0.round() as i32, p.1.round() as i32), transform));
+            s += w;
+        }
+
+        if glyphs.is_empty() {
+            let (mid, _) = point_along(&path, length / 2.0);
+            let mid = (mid.0.round() as i32, mid.1.round() as i32);
+            return backend.draw_text(text, &centered, mid);
+        }
+        for (c, pos, transform) in glyphs {
+            let style = centered.transform(transform);
+            backend.draw_text(c.encode_utf8(&mut [0; 4]), &style, pos)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_curved_text() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        // Along the upward path, one call per character, then once for the diagonal
+        m.drop_check(|b| assert_eq!(b.num_draw_text_call, 5));
+    });
+    let style = ("sans-serif", 12);
+    da.draw(&CurvedText::new(
+        "30°E",
+        vec![(150, 290), (152, 150), (150, 10)],
+        style,
+    ))
+    .unwrap();
+    da.draw(&CurvedText::new("30°E", vec![(0, 0), (300, 300)], style))
+        .unwrap();
+}
@@ -711,6 +711,7 @@ pub fn register_font(
 | Name    |  Description | Additional Dependency |Default?|
 |---------|--------------|--------|------------|
 | datetime | Enable the date and time coordinate support | chrono | Yes |
+| proj | Enable the map projections going through the PROJ library | proj | Yes |
 
 - Element, series and util functions
 